@@ -498,6 +498,7 @@ describe('cloud server api', () => {
             headers: {
                 ...authHeaders,
                 'content-type': 'application/json',
+                'if-match': '*',
             },
             body: JSON.stringify({
                 tasks: [],
@@ -586,6 +587,7 @@ describe('cloud server api', () => {
             headers: {
                 ...authHeaders,
                 'content-type': 'application/json',
+                'if-match': '*',
             },
             body: JSON.stringify({
                 ...base,
@@ -599,6 +601,7 @@ describe('cloud server api', () => {
             headers: {
                 ...authHeaders,
                 'content-type': 'application/json',
+                'if-match': '*',
             },
             body: JSON.stringify({
                 ...base,
@@ -617,6 +620,121 @@ describe('cloud server api', () => {
         expect(taskIds.has(taskB.id)).toBe(true);
     });
 
+    test('rejects /v1/data writes with a stale If-Match ETag', async () => {
+        const payload = {
+            tasks: [{
+                id: 'task-a',
+                title: 'Task A',
+                status: 'inbox',
+                createdAt: '2026-01-01T00:00:00.000Z',
+                updatedAt: '2026-01-01T00:00:00.000Z',
+            }],
+            projects: [],
+            sections: [],
+            areas: [],
+            settings: {},
+        };
+        const put = (ifMatch: string) => fetch(`${baseUrl}/v1/data`, {
+            method: 'PUT',
+            headers: {
+                ...authHeaders,
+                'content-type': 'application/json',
+                'if-match': ifMatch,
+            },
+            body: JSON.stringify(payload),
+        });
+
+        const initial = await fetch(`${baseUrl}/v1/data`, { headers: authHeaders });
+        const etag = initial.headers.get('etag');
+        expect(etag).toBeTruthy();
+
+        const firstPut = await put(etag!);
+        expect(firstPut.status).toBe(200);
+        const nextEtag = firstPut.headers.get('etag');
+        expect(nextEtag).toBeTruthy();
+        expect(nextEtag).not.toBe(etag);
+
+        const stalePut = await put(etag!);
+        expect(stalePut.status).toBe(412);
+
+        const current = await fetch(`${baseUrl}/v1/data`, { headers: authHeaders });
+        expect(current.headers.get('etag')).toBe(nextEtag);
+    });
+
+    test('checks /v1/data write preconditions and merges writes from clients without one', async () => {
+        const payload = JSON.stringify({ tasks: [], projects: [], sections: [], areas: [], settings: {} });
+        const put = (precondition: Record<string, string>) => fetch(`${baseUrl}/v1/data`, {
+            method: 'PUT',
+            headers: { ...authHeaders, 'content-type': 'application/json', ...precondition },
+            body: payload,
+        });
+
+        // If-None-Match: * only creates the data; once it exists the client has to read it first.
+        const created = await put({ 'if-none-match': '*' });
+        expect(created.status).toBe(200);
+        expect((await put({ 'if-none-match': '*' })).status).toBe(412);
+
+        // The ETag served with a read is the one of the stored bytes, as returned by the write.
+        const read = await fetch(`${baseUrl}/v1/data`, { headers: authHeaders });
+        const etag = read.headers.get('etag');
+        expect(etag).toBe(created.headers.get('etag'));
+        const updated = await put({ 'if-match': etag! });
+        expect(updated.status).toBe(200);
+
+        // Older clients send no precondition; their writes are merged into whatever is stored.
+        const legacy = await put({});
+        expect(legacy.status).toBe(200);
+        expect(legacy.headers.get('etag')).toBeTruthy();
+        // An ETag that is not the stored one is still refused.
+        expect((await put({ 'if-match': '"stale"' })).status).toBe(412);
+    });
+
+    test('stores end-to-end encrypted /v1/data payloads as sent', async () => {
+        const envelope = {
+            mindwtrEncrypted: 1,
+            keyId: '0123456789abcdef',
+            kdf: { algorithm: 'argon2id', salt: 'c2FsdA==' },
+            nonce: 'bm9uY2U=',
+            ciphertext: 'Y2lwaGVydGV4dA==',
+        };
+        const putJson = (body: unknown, ifMatch?: string) => fetch(`${baseUrl}/v1/data`, {
+            method: 'PUT',
+            headers: {
+                ...authHeaders,
+                'content-type': 'application/json',
+                ...(ifMatch ? { 'if-match': ifMatch } : {}),
+            },
+            body: JSON.stringify(body),
+        });
+
+        const initial = await fetch(`${baseUrl}/v1/data`, { headers: authHeaders });
+        const etag = initial.headers.get('etag');
+        const sealed = await putJson(envelope, etag!);
+        expect(sealed.status).toBe(200);
+        const sealedEtag = sealed.headers.get('etag');
+        expect(await putJson({ ...envelope, nonce: 'b3RoZXI=' }, etag!).then((response) => response.status)).toBe(412);
+
+        const read = await fetch(`${baseUrl}/v1/data`, { headers: authHeaders });
+        expect(read.status).toBe(200);
+        expect(read.headers.get('etag')).toBe(sealedEtag);
+        expect(await read.json()).toEqual(envelope);
+
+        const tasks = await fetch(`${baseUrl}/v1/tasks`, { headers: authHeaders });
+        expect(tasks.status).toBe(409);
+        const created = await fetch(`${baseUrl}/v1/tasks`, {
+            method: 'POST',
+            headers: { ...authHeaders, 'content-type': 'application/json' },
+            body: JSON.stringify({ title: 'Would overwrite the ciphertext' }),
+        });
+        expect(created.status).toBe(409);
+
+        const plaintext = { tasks: [], projects: [], sections: [], areas: [], settings: {} };
+        expect((await putJson(plaintext, sealedEtag!)).status).toBe(200);
+        const decrypted = await fetch(`${baseUrl}/v1/data`, { headers: authHeaders });
+        expect((await decrypted.json()).tasks).toEqual([]);
+        expect((await fetch(`${baseUrl}/v1/tasks`, { headers: authHeaders })).status).toBe(200);
+    });
+
     test('rejects /v1/data merge when existing on-disk state is invalid', async () => {
         const key = __cloudTestUtils.tokenToKey(integrationToken);
         const filePath = join(dataDir, `${key}.json`);
@@ -633,6 +751,7 @@ describe('cloud server api', () => {
             headers: {
                 ...authHeaders,
                 'content-type': 'application/json',
+                'if-match': '*',
             },
             body: JSON.stringify({
                 tasks: [{
@@ -667,6 +786,7 @@ describe('cloud server api', () => {
             headers: {
                 ...authHeaders,
                 'content-type': 'application/json',
+                'if-match': '*',
             },
             body: JSON.stringify({
                 ...base,
@@ -686,6 +806,7 @@ describe('cloud server api', () => {
             headers: {
                 ...authHeaders,
                 'content-type': 'application/json',
+                'if-match': '*',
             },
             body: JSON.stringify({
                 ...base,
@@ -721,6 +842,7 @@ describe('cloud server api', () => {
             headers: {
                 ...authHeaders,
                 'content-type': 'application/json',
+                'if-match': '*',
             },
             body: JSON.stringify({
                 ...base,
@@ -741,6 +863,7 @@ describe('cloud server api', () => {
             headers: {
                 ...authHeaders,
                 'content-type': 'application/json',
+                'if-match': '*',
             },
             body: JSON.stringify({
                 ...base,
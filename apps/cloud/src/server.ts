@@ -254,8 +254,9 @@ function jsonResponse(body: unknown, init: ResponseInit = {}) {
     const headers = new Headers(init.headers);
     headers.set('Content-Type', 'application/json; charset=utf-8');
     headers.set('Access-Control-Allow-Origin', corsOrigin);
-    headers.set('Access-Control-Allow-Headers', 'Authorization, Content-Type');
+    headers.set('Access-Control-Allow-Headers', 'Authorization, Content-Type, If-Match, If-None-Match');
     headers.set('Access-Control-Allow-Methods', 'GET,PUT,POST,PATCH,DELETE,OPTIONS');
+    headers.set('Access-Control-Expose-Headers', 'ETag');
     return new Response(JSON.stringify(body, null, 2), { ...init, headers });
 }
 
@@ -349,6 +350,19 @@ function isValidIsoTimestamp(value: unknown): boolean {
     return Number.isFinite(parsed);
 }
 
+/**
+ * An end-to-end encrypted payload from the desktop app. The server cannot read or merge it,
+ * so it is stored as sent and the task API is unavailable while it is in place.
+ */
+function isEncryptedSyncPayload(value: unknown): boolean {
+    if (!isRecord(value)) return false;
+    return typeof value.mindwtrEncrypted === 'number'
+        && typeof value.keyId === 'string'
+        && typeof value.nonce === 'string'
+        && typeof value.ciphertext === 'string'
+        && isRecord(value.kdf);
+}
+
 function validateAppData(value: unknown): { ok: true; data: Record<string, unknown> } | { ok: false; error: string } {
     if (!isRecord(value)) return { ok: false, error: 'Invalid data: expected an object' };
     const tasks = value.tasks;
@@ -541,9 +555,35 @@ function readData(filePath: string): any | null {
     }
 }
 
-function writeData(filePath: string, data: unknown) {
+function etagOf(bytes: Buffer | string): string {
+    return `"${createHash('sha256').update(bytes).digest('hex')}"`;
+}
+
+function dataEtag(filePath: string): string | null {
+    if (!existsSync(filePath)) return null;
+    try {
+        return etagOf(readFileSync(filePath));
+    } catch {
+        return null;
+    }
+}
+
+/** Parses the stored data and tags it from the same read, so a body never goes out with another version's ETag. */
+function readDataWithEtag(filePath: string): { data: unknown; etag: string } | null {
+    try {
+        const raw = readFileSync(filePath);
+        return { data: JSON.parse(raw.toString('utf8')), etag: etagOf(raw) };
+    } catch {
+        return null;
+    }
+}
+
+/** Writes the data and returns the ETag of what was written. */
+function writeData(filePath: string, data: unknown): string {
     mkdirSync(dirname(filePath), { recursive: true });
-    writeFileSync(filePath, JSON.stringify(data, null, 2));
+    const raw = JSON.stringify(data, null, 2);
+    writeFileSync(filePath, raw);
+    return etagOf(raw);
 }
 
 function ensureWritableDir(dirPath: string): boolean {
@@ -587,6 +627,7 @@ export const __cloudTestUtils = {
     isAuthorizedToken,
     toRateLimitRoute,
     validateAppData,
+    isEncryptedSyncPayload,
     asStatus,
     pickTaskList,
     readJsonBody,
@@ -630,6 +671,8 @@ export async function startCloudServer(options: CloudServerOptions = {}): Promis
     const dataDir = String(options.dataDir ?? process.env.MINDWTR_CLOUD_DATA_DIR ?? join(process.cwd(), 'data'));
 
     const rateLimits = new Map<string, RateLimitState>();
+    // Namespaces already warned about writes from clients that predate conditional writes.
+    const unconditionalWriters = new Set<string>();
     const windowMs = Number(options.windowMs ?? process.env.MINDWTR_CLOUD_RATE_WINDOW_MS ?? 60_000);
     const maxPerWindow = Number(options.maxPerWindow ?? process.env.MINDWTR_CLOUD_RATE_MAX ?? 120);
     const maxAttachmentPerWindow = Number(
@@ -741,6 +784,9 @@ export async function startCloudServer(options: CloudServerOptions = {}): Promis
                 const rateLimitResponse = checkRateLimit(rateKey, maxPerWindow);
                 if (rateLimitResponse) return rateLimitResponse;
                 const filePath = join(dataDir, `${key}.json`);
+                if (isEncryptedSyncPayload(readData(filePath))) {
+                    return errorResponse('Data is end-to-end encrypted; the task API is unavailable', 409);
+                }
 
                 if (req.method === 'GET' && pathname === '/v1/tasks') {
                     const query = url.searchParams.get('query') || '';
@@ -946,18 +992,22 @@ export async function startCloudServer(options: CloudServerOptions = {}): Promis
                 const filePath = join(dataDir, `${key}.json`);
 
                 if (req.method === 'GET') {
-                    if (!existsSync(filePath)) {
-                        const emptyData: AppData = { tasks: [], projects: [], sections: [], areas: [], settings: {} };
-                        await withWriteLock(key, async () => {
-                            if (!existsSync(filePath)) writeData(filePath, emptyData);
-                        });
-                        return jsonResponse(emptyData);
-                    }
-                    const data = readData(filePath);
-                    if (!data) return errorResponse('Failed to read data', 500);
-                    const validated = validateAppData(data);
-                    if (!validated.ok) return errorResponse(validated.error, 500);
-                    return jsonResponse(validated.data);
+                    // Reads take the write lock too, so the ETag always belongs to the body it is sent with.
+                    return await withWriteLock(key, async () => {
+                        if (!existsSync(filePath)) {
+                            const emptyData: AppData = { tasks: [], projects: [], sections: [], areas: [], settings: {} };
+                            return jsonResponse(emptyData, { headers: { ETag: writeData(filePath, emptyData) } });
+                        }
+                        const stored = readDataWithEtag(filePath);
+                        if (!stored || !stored.data) return errorResponse('Failed to read data', 500);
+                        const headers = { ETag: stored.etag };
+                        if (isEncryptedSyncPayload(stored.data)) {
+                            return jsonResponse(stored.data, { headers });
+                        }
+                        const validated = validateAppData(stored.data);
+                        if (!validated.ok) return errorResponse(validated.error, 500);
+                        return jsonResponse(validated.data, { headers });
+                    });
                 }
 
                 if (req.method === 'PUT') {
@@ -968,18 +1018,48 @@ export async function startCloudServer(options: CloudServerOptions = {}): Promis
                     }
                     if (!body) return errorResponse('Missing body');
                     if (typeof body !== 'object') return errorResponse('Invalid JSON body');
+                    // A write names the version it replaces: If-Match with the ETag it read,
+                    // If-None-Match: * when it read nothing, or If-Match: * to merge into whatever is stored.
+                    // Clients from before conditional writes send neither and keep the old merge behaviour.
+                    let ifMatch = req.headers.get('if-match')?.trim();
+                    const ifNoneMatch = req.headers.get('if-none-match')?.trim();
+                    if (!ifMatch && ifNoneMatch !== '*') {
+                        ifMatch = '*';
+                        if (!unconditionalWriters.has(key)) {
+                            unconditionalWriters.add(key);
+                            logWarn('PUT /v1/data without If-Match; merging as before. Update the client to get conflict detection.');
+                        }
+                    }
+                    const isStale = () => {
+                        const current = dataEtag(filePath);
+                        return ifMatch ? ifMatch !== '*' && ifMatch !== current : current !== null;
+                    };
+                    if (isEncryptedSyncPayload(body)) {
+                        // Ciphertext cannot be merged; the client merged before encrypting.
+                        return await withWriteLock(key, async () => {
+                            if (isStale()) {
+                                return errorResponse('Data changed since it was read', 412);
+                            }
+                            return jsonResponse({ ok: true }, { headers: { ETag: writeData(filePath, body) } });
+                        });
+                    }
                     const validated = validateAppData(body);
                     if (!validated.ok) return errorResponse(validated.error, 400);
                     return await withWriteLock(key, async () => {
-                        const existingData = loadAppData(filePath);
+                        if (isStale()) {
+                            return errorResponse('Data changed since it was read', 412);
+                        }
+                        // Plaintext replaces an encrypted copy (encryption was turned off) rather than merging with it.
+                        const existingData = isEncryptedSyncPayload(readData(filePath))
+                            ? { ...DEFAULT_DATA }
+                            : loadAppData(filePath);
                         const incomingData = validated.data as AppData;
                         const mergedData = mergeAppData(existingData, incomingData);
                         const validatedMerged = validateAppData(mergedData);
                         if (!validatedMerged.ok) {
                             return errorResponse(`Invalid merged data: ${validatedMerged.error}`, 500);
                         }
-                        writeData(filePath, validatedMerged.data);
-                        return jsonResponse({ ok: true });
+                        return jsonResponse({ ok: true }, { headers: { ETag: writeData(filePath, validatedMerged.data) } });
                     });
                 }
             }
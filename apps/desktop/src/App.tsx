@@ -21,7 +21,15 @@ import { QuickAddModal } from './components/QuickAddModal';
 import { CloseBehaviorModal } from './components/CloseBehaviorModal';
 import { startDesktopNotifications, stopDesktopNotifications } from './lib/notification-service';
 import { SyncService } from './lib/sync-service';
-import type { ExternalSyncChange, ExternalSyncChangeResolution } from './lib/sync-service';
+import type {
+    AutoSyncRequest,
+    ConfigChangedEvent,
+    ExternalSyncChange,
+    ExternalSyncChangeResolution,
+    SyncConflictCopiesReport,
+    SyncQuarantineReport,
+} from './lib/sync-service';
+import { ExternalCalendarService } from './lib/external-calendar-service';
 import * as LocalDataWatcher from './lib/local-data-watcher';
 import { isFlatpakRuntime, isTauriRuntime } from './lib/runtime';
 import { logError } from './lib/app-log';
@@ -47,7 +55,6 @@ function App() {
     const { t, language, setLanguage } = useLanguage();
     const isActiveRef = useRef(true);
     const lastAutoSyncRef = useRef(0);
-    const syncDebounceTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
     const initialSyncTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
     const syncInFlightRef = useRef<Promise<void> | null>(null);
     const syncQueuedRef = useRef(false);
@@ -225,6 +232,18 @@ function App() {
                 const { url } = await SyncService.getCloudConfig();
                 return Boolean(url);
             }
+            if (backend === 's3') {
+                const { bucket } = await SyncService.getS3Config({ silent: true });
+                return Boolean(bucket);
+            }
+            if (backend === 'sftp') {
+                const { host } = await SyncService.getSftpConfig({ silent: true });
+                return Boolean(host);
+            }
+            if (backend === 'git') {
+                const { repoPath } = await SyncService.getGitConfig({ silent: true });
+                return Boolean(repoPath);
+            }
             return false;
         };
 
@@ -281,20 +300,34 @@ function App() {
             queueSync().catch((error) => reportError('Sync failed', error));
         };
 
-        // Auto-sync on data changes with debounce
+        // Data changes are debounced by the backend scheduler, which asks for a sync when due
         const storeUnsubscribe = useTaskStore.subscribe((state, prevState) => {
             if (state.lastDataChangeAt === prevState.lastDataChangeAt) return;
-            const hadTimer = !!syncDebounceTimerRef.current;
-            if (syncDebounceTimerRef.current) {
-                clearTimeout(syncDebounceTimerRef.current);
-            }
-            const debounceMs = hadTimer ? 5000 : 2000;
-            syncDebounceTimerRef.current = setTimeout(() => {
-                if (!isActiveRef.current) return;
-                queueSync().catch((error) => reportError('Sync failed', error));
-            }, debounceMs);
+            SyncService.reportLocalChange();
         });
 
+        let unlistenAutoSync: (() => void) | null = null;
+        if (isTauriRuntime()) {
+            import('@tauri-apps/api/event')
+                .then(({ listen }) => listen<AutoSyncRequest>('auto-sync-requested', (event) => {
+                    if (!isActiveRef.current) return;
+                    const { reason, paths } = event.payload;
+                    if (reason === 'sync-folder') {
+                        SyncService.handleSyncFolderChange(paths).catch((error) => reportError('Sync failed', error));
+                        return;
+                    }
+                    queueSync().catch((error) => reportError('Sync failed', error));
+                }))
+                .then((unlisten) => {
+                    if (disposed) {
+                        unlisten();
+                    } else {
+                        unlistenAutoSync = unlisten;
+                    }
+                })
+                .catch((error) => reportError('Auto-sync listener failed', error));
+        }
+
         // Background/on-resume sync (focus/blur) and initial auto-sync
         window.addEventListener('focus', focusListener);
         window.addEventListener('blur', blurListener);
@@ -313,8 +346,8 @@ function App() {
                 unlistenClose();
             }
             storeUnsubscribe();
-            if (syncDebounceTimerRef.current) {
-                clearTimeout(syncDebounceTimerRef.current);
+            if (unlistenAutoSync) {
+                unlistenAutoSync();
             }
             if (initialSyncTimerRef.current) {
                 clearTimeout(initialSyncTimerRef.current);
@@ -326,6 +359,49 @@ function App() {
         };
     }, [fetchData, setError]);
 
+    useEffect(() => {
+        if (!isTauriRuntime()) return;
+        let unlisten: (() => void) | undefined;
+        let cancelled = false;
+
+        const adoptConfigCalendars = async () => {
+            // Synced settings carry their own calendar list; take the one from the config file.
+            if (Array.isArray(useTaskStore.getState().settings?.externalCalendars)) {
+                await updateSettings({ externalCalendars: await ExternalCalendarService.getCalendars() });
+            }
+            ExternalCalendarService.notifyChanged();
+        };
+
+        const setup = async () => {
+            const { listen } = await import('@tauri-apps/api/event');
+            const stop = await listen<ConfigChangedEvent>('config-changed', (event) => {
+                const { keys, errors } = event.payload;
+                if (errors.length > 0) {
+                    showToast(`Config file not applied, keeping the previous settings: ${errors.join('; ')}`, 'error', 8000);
+                    return;
+                }
+                SyncService.applyConfigChange()
+                    .catch((error) => void logError(error, { scope: 'sync', step: 'applyConfigChange' }));
+                if (keys.includes('external_calendars')) {
+                    adoptConfigCalendars()
+                        .catch((error) => void logError(error, { scope: 'calendar', step: 'applyConfigChange' }));
+                }
+            });
+            if (cancelled) {
+                stop();
+            } else {
+                unlisten = stop;
+            }
+        };
+
+        setup().catch((error) => void logError(error, { scope: 'app', step: 'configListener' }));
+
+        return () => {
+            cancelled = true;
+            if (unlisten) unlisten();
+        };
+    }, [showToast, updateSettings]);
+
     useEffect(() => {
         if (!isTauriRuntime()) return;
         let unlisten: (() => void) | undefined;
@@ -369,6 +445,48 @@ function App() {
         };
     }, [closeBehavior, closePromptOpen, hideToTray, isFlatpak, quitApp, setClosePromptRememberValue, setError, showTray]);
 
+    useEffect(() => {
+        if (!isTauriRuntime()) return;
+        const unlisteners: Array<() => void> = [];
+        let cancelled = false;
+
+        const setup = async () => {
+            const { listen } = await import('@tauri-apps/api/event');
+            const stopCopies = await listen<SyncConflictCopiesReport>('sync-conflict-copies', (event) => {
+                const { files, mergedItems } = event.payload;
+                const copies = `${files.length} conflict cop${files.length === 1 ? 'y' : 'ies'}`;
+                showToast(
+                    mergedItems > 0
+                        ? `Merged ${copies} from the sync folder (${mergedItems} item${mergedItems === 1 ? '' : 's'} updated).`
+                        : `Archived ${copies} from the sync folder; nothing new to merge.`,
+                    'info',
+                    6000
+                );
+            });
+            const stopQuarantine = await listen<SyncQuarantineReport>('sync-file-quarantined', (event) => {
+                const { file, reason, recoveredFromBackup } = event.payload;
+                showToast(
+                    recoveredFromBackup
+                        ? `Sync file ${file} was damaged (${reason}). A copy was kept in quarantine and the file was left in place. Using the last good backup.`
+                        : `Sync file ${file} was damaged (${reason}). A copy was kept in quarantine and the file was left in place.`,
+                    'error',
+                    8000
+                );
+            });
+            unlisteners.push(stopCopies, stopQuarantine);
+            if (cancelled) {
+                unlisteners.forEach((stop) => stop());
+            }
+        };
+
+        setup().catch((error) => void logError(error, { scope: 'sync', step: 'syncFileListeners' }));
+
+        return () => {
+            cancelled = true;
+            unlisteners.forEach((stop) => stop());
+        };
+    }, [showToast]);
+
     useEffect(() => {
         if (!isTauriRuntime()) return;
         if (windowDecorations === undefined) return;
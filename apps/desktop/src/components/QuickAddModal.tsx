@@ -17,7 +17,7 @@ import { cn } from '../lib/utils';
 import { isTauriRuntime } from '../lib/runtime';
 import { reportError } from '../lib/report-error';
 import { logWarn } from '../lib/app-log';
-import { loadAIKey } from '../lib/ai-config';
+import { hasAIKey, loadAIKey } from '../lib/ai-config';
 import { encodeWav, resampleAudio } from '../lib/audio-utils';
 import { processAudioCapture, type SpeechToTextResult } from '../lib/speech-to-text';
 import { DEFAULT_WHISPER_MODEL } from '../lib/speech-models';
@@ -415,11 +415,12 @@ export function QuickAddModal() {
                         : DEFAULT_WHISPER_MODEL
             );
             const apiKey = provider === 'whisper' ? '' : await loadAIKey(provider).catch(() => '');
+            const hasKey = provider === 'whisper' ? false : await hasAIKey(provider).catch(() => false);
             const modelPath = provider === 'whisper' ? speech?.offlineModelPath : undefined;
             const speechReady = speech?.enabled
                 ? provider === 'whisper'
                     ? Boolean(modelPath)
-                    : Boolean(apiKey)
+                    : hasKey
                 : false;
             const saveAudioAttachments = settings.gtd?.saveAudioAttachments !== false || !speechReady;
 
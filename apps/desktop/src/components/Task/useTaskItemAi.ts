@@ -8,7 +8,7 @@ import {
     createAIProvider,
 } from '@mindwtr/core';
 import { isTauriRuntime } from '../../lib/runtime';
-import { buildAIConfig, buildCopilotConfig, isAIKeyRequired, loadAIKey } from '../../lib/ai-config';
+import { buildAIConfig, buildCopilotConfig, hasAIKey, isAIKeyRequired, loadAIKey } from '../../lib/ai-config';
 import { logWarn } from '../../lib/app-log';
 
 type TaskItemAiContext = {
@@ -55,6 +55,7 @@ export function useTaskItemAi({
     const keyRequired = isAIKeyRequired(settings);
 
     const [aiKey, setAiKey] = useState('');
+    const [aiHasKey, setAiHasKey] = useState(false);
     const [aiClarifyResponse, setAiClarifyResponse] = useState<ClarifyResponse | null>(null);
     const [aiError, setAiError] = useState<string | null>(null);
     const [aiBreakdownSteps, setAiBreakdownSteps] = useState<string[] | null>(null);
@@ -69,12 +70,16 @@ export function useTaskItemAi({
 
     useEffect(() => {
         let active = true;
-        loadAIKey(aiProvider)
-            .then((key) => {
-                if (active) setAiKey(key);
+        Promise.all([loadAIKey(aiProvider), hasAIKey(aiProvider)])
+            .then(([key, hasKey]) => {
+                if (!active) return;
+                setAiKey(key);
+                setAiHasKey(hasKey);
             })
             .catch(() => {
-                if (active) setAiKey('');
+                if (!active) return;
+                setAiKey('');
+                setAiHasKey(false);
             });
         return () => {
             active = false;
@@ -82,7 +87,7 @@ export function useTaskItemAi({
     }, [aiProvider]);
 
     useEffect(() => {
-        if (!aiEnabled || (keyRequired && !aiKey)) {
+        if (!aiEnabled || (keyRequired && !aiHasKey)) {
             setCopilotSuggestion(null);
             return;
         }
@@ -148,7 +153,7 @@ export function useTaskItemAi({
                 copilotAbortRef.current = null;
             }
         };
-    }, [aiEnabled, aiKey, aiProvider, copilotModel, editContexts, editDescription, editTitle, keyRequired, settings, tagOptions, timeEstimatesEnabled]);
+    }, [aiEnabled, aiHasKey, aiKey, aiProvider, copilotModel, editContexts, editDescription, editTitle, keyRequired, settings, tagOptions, timeEstimatesEnabled]);
 
     useEffect(() => {
         copilotMountedRef.current = true;
@@ -185,12 +190,12 @@ export function useTaskItemAi({
             setAiError(t('ai.disabledBody'));
             return null;
         }
-        if (keyRequired && !aiKey) {
+        if (keyRequired && !aiHasKey) {
             setAiError(t('ai.missingKeyBody'));
             return null;
         }
         return createAIProvider(buildAIConfig(settings, aiKey));
-    }, [aiEnabled, aiKey, keyRequired, settings, t]);
+    }, [aiEnabled, aiHasKey, aiKey, keyRequired, settings, t]);
 
     const resetCopilotDraft = useCallback(() => {
         setCopilotApplied(false);
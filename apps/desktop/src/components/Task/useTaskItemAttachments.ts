@@ -6,6 +6,7 @@ import { BaseDirectory, readFile, readTextFile, size } from '@tauri-apps/plugin-
 import { normalizeAttachmentInput } from '../../lib/attachment-utils';
 import { isTauriRuntime } from '../../lib/runtime';
 import { logWarn } from '../../lib/app-log';
+import { SyncService } from '../../lib/sync-service';
 import {
     isAudioAttachment,
     isImageAttachment,
@@ -169,7 +170,7 @@ export function useTaskItemAttachments({ task, t }: UseTaskItemAttachmentsProps)
         };
     }, [audioAttachment, closeAudio, closeImage, closeText, imageAttachment, textAttachment]);
 
-    const openAttachment = useCallback((attachment: Attachment) => {
+    const showAttachment = useCallback((attachment: Attachment) => {
         if (isAudioAttachment(attachment)) {
             const requestId = audioLoadRequestRef.current + 1;
             audioLoadRequestRef.current = requestId;
@@ -225,6 +226,22 @@ export function useTaskItemAttachments({ task, t }: UseTaskItemAttachmentsProps)
         void openExternal(attachment.uri);
     }, [loadTextAttachment, openExternal, resolveAudioBlobSource, t]);
 
+    const openAttachment = useCallback((attachment: Attachment) => {
+        if (!isTauriRuntime() || attachment.kind !== 'file' || !attachment.cloudKey) {
+            showAttachment(attachment);
+            return;
+        }
+        setAttachmentError(null);
+        void SyncService.ensureAttachmentAvailable(attachment)
+            .then((uri) => {
+                showAttachment(uri === attachment.uri ? attachment : { ...attachment, uri, localStatus: 'available' });
+            })
+            .catch((error) => {
+                const message = error instanceof Error ? error.message : String(error);
+                setAttachmentError(message || t('attachments.fileNotSupported'));
+            });
+    }, [showAttachment, t]);
+
     useEffect(() => {
         return () => {
             audioLoadRequestRef.current += 1;
@@ -10,6 +10,7 @@ import { checkBudget } from '../../config/performanceBudgets';
 import { TaskItem } from '../TaskItem';
 import { resolveAreaFilter, taskMatchesAreaFilter } from '../../lib/area-filter';
 import { fetchExternalCalendarEvents } from '../../lib/external-calendar-events';
+import { ExternalCalendarService } from '../../lib/external-calendar-service';
 
 const dayKey = (date: Date) => format(date, 'yyyy-MM-dd');
 
@@ -52,6 +53,7 @@ export function CalendarView() {
     const [externalEvents, setExternalEvents] = useState<ExternalCalendarEvent[]>([]);
     const [externalError, setExternalError] = useState<string | null>(null);
     const [isExternalLoading, setIsExternalLoading] = useState(false);
+    const [externalCalendarsVersion, setExternalCalendarsVersion] = useState(0);
     const [editingTimeTaskId, setEditingTimeTaskId] = useState<string | null>(null);
     const [editingTimeValue, setEditingTimeValue] = useState<string>('');
     const calendarBodyRef = useRef<HTMLDivElement | null>(null);
@@ -283,7 +285,11 @@ export function CalendarView() {
         return () => {
             cancelled = true;
         };
-    }, [currentMonth]);
+    }, [currentMonth, externalCalendarsVersion]);
+
+    useEffect(() => ExternalCalendarService.subscribe(() => {
+        setExternalCalendarsVersion((version) => version + 1);
+    }), []);
 
     const scheduleCandidates = useMemo(() => {
         if (!selectedDate) return [];
@@ -103,6 +103,17 @@ const maskCalendarUrl = (url: string): string => {
     return `${protocol}${host}/${suffix}`;
 };
 
+const formatTimeAgo = (iso: string, locale: string): string => {
+    const elapsedMs = Date.now() - Date.parse(iso);
+    if (!Number.isFinite(elapsedMs)) return iso;
+    const formatter = new Intl.RelativeTimeFormat(locale || undefined, { numeric: 'auto' });
+    const minutes = Math.round(Math.max(0, elapsedMs) / 60_000);
+    if (minutes < 60) return formatter.format(-minutes, 'minute');
+    const hours = Math.round(minutes / 60);
+    if (hours < 48) return formatter.format(-hours, 'hour');
+    return formatter.format(-Math.round(hours / 24), 'day');
+};
+
 export function SettingsView() {
     const perf = usePerformanceMonitor('SettingsView');
     const [page, setPage] = useState<SettingsPage>('main');
@@ -226,6 +237,10 @@ export function SettingsView() {
         aiThinkingBudget,
         anthropicThinkingEnabled,
         aiApiKey,
+        aiHasKey,
+        aiProviders,
+        aiProvidersError,
+        aiKeyless,
         speechEnabled,
         speechProvider,
         speechModel,
@@ -234,6 +249,7 @@ export function SettingsView() {
         speechMode,
         speechFieldStrategy,
         speechApiKey,
+        speechHasKey,
         speechOfflineReady,
         speechOfflineSize,
         speechDownloadState,
@@ -244,6 +260,8 @@ export function SettingsView() {
         onSpeechProviderChange,
         onToggleAnthropicThinking,
         onAiApiKeyChange,
+        onSaveAIProviders,
+        onChooseLocalModel,
         onSpeechApiKeyChange,
         onDownloadWhisperModel,
         onDeleteWhisperModel,
@@ -817,7 +835,7 @@ export function SettingsView() {
         { id: 'main', icon: Monitor, label: t.general, keywords: [t.appearance, t.density, t.language, t.weekStart, t.dateFormat, t.keybindings, t.windowDecorations, t.closeBehavior, t.showTray, 'theme', 'dark mode', 'light mode'] },
         { id: 'gtd', icon: ListChecks, label: t.gtd, keywords: ['auto-archive', 'priorities', 'time estimates', 'pomodoro', 'capture', 'inbox processing', '2-minute rule', 'task editor'] },
         { id: 'notifications', icon: Bell, label: t.notifications, keywords: ['review reminders', 'weekly review', 'daily digest', 'morning', 'evening'] },
-        { id: 'sync', icon: Database, label: t.sync, keywords: ['file sync', 'WebDAV', 'cloud', 'sync now', 'attachments', 'diagnostics', 'logging'] },
+        { id: 'sync', icon: Database, label: t.sync, keywords: ['file sync', 'WebDAV', 'cloud', 'S3', 'MinIO', 'Git', 'SFTP', 'SSH', 'sync now', 'attachments', 'diagnostics', 'logging'] },
         { id: 'ai', icon: Sparkles, label: t.ai, keywords: ['OpenAI', 'Gemini', 'Anthropic', 'API key', 'speech', 'whisper', 'copilot', 'model'] },
         { id: 'calendar', icon: CalendarDays, label: t.calendar, keywords: ['external calendar', 'iCal', 'subscription', 'URL'] },
         { id: 'about', icon: Info, label: t.about, badge: hasUpdateBadge, badgeLabel: t.updateAvailable, keywords: ['version', 'update', 'license', 'sponsor'] },
@@ -826,6 +844,11 @@ export function SettingsView() {
     const {
         syncPath,
         setSyncPath,
+        syncFileLayout,
+        syncFileLayoutBusy,
+        syncIntervalMinutes,
+        syncPreview,
+        syncPreviewBusy,
         isSyncing,
         syncQueued,
         syncLastResult,
@@ -844,6 +867,7 @@ export function SettingsView() {
         setCloudUrl,
         cloudToken,
         setCloudToken,
+        cloudHasToken,
         cloudProvider,
         dropboxAppKey,
         dropboxConfigured,
@@ -851,11 +875,73 @@ export function SettingsView() {
         dropboxBusy,
         dropboxRedirectUri,
         dropboxTestState,
+        s3Endpoint,
+        setS3Endpoint,
+        s3Bucket,
+        setS3Bucket,
+        s3Prefix,
+        setS3Prefix,
+        s3Region,
+        setS3Region,
+        s3AccessKeyId,
+        setS3AccessKeyId,
+        s3SecretKey,
+        setS3SecretKey,
+        s3HasSecretKey,
+        s3Busy,
+        s3TestState,
+        sftpHost,
+        setSftpHost,
+        sftpPort,
+        setSftpPort,
+        sftpUsername,
+        setSftpUsername,
+        sftpRemotePath,
+        setSftpRemotePath,
+        sftpKeyPath,
+        setSftpKeyPath,
+        sftpKeyPassphrase,
+        setSftpKeyPassphrase,
+        sftpHasKeyPassphrase,
+        sftpBusy,
+        sftpTestState,
+        gitRepoPath,
+        setGitRepoPath,
+        gitRemote,
+        setGitRemote,
+        gitBranch,
+        setGitBranch,
+        gitBusy,
+        gitTestState,
         snapshots,
         isLoadingSnapshots,
         isRestoringSnapshot,
+        syncJournal,
+        secretVault,
+        managedConfigKeys,
+        secretVaultPassphrase,
+        setSecretVaultPassphrase,
+        secretVaultPassphraseConfirm,
+        setSecretVaultPassphraseConfirm,
+        secretVaultBusy,
+        syncEncryption,
+        syncEncryptionPassphrase,
+        setSyncEncryptionPassphrase,
+        syncEncryptionPassphraseConfirm,
+        setSyncEncryptionPassphraseConfirm,
+        syncEncryptionBusy,
         handleSaveSyncPath,
         handleChangeSyncLocation,
+        handleSetSyncFileLayout,
+        handleSetSyncIntervalMinutes,
+        handlePreviewSync,
+        handleUnlockSecretVault,
+        handleCreateSecretVault,
+        handleLockSecretVault,
+        handleAllowPlaintextSecrets,
+        handleEnableSyncEncryption,
+        handleRotateSyncEncryptionKey,
+        handleDisableSyncEncryption,
         handleSetSyncBackend,
         handleSaveWebDav,
         handleSaveCloud,
@@ -863,6 +949,14 @@ export function SettingsView() {
         handleConnectDropbox,
         handleDisconnectDropbox,
         handleTestDropboxConnection,
+        handleSaveS3,
+        handleTestS3Connection,
+        handleSaveSftp,
+        handleChooseSftpKey,
+        handleTestSftpConnection,
+        handleSaveGit,
+        handleChooseGitRepo,
+        handleTestGitConnection,
         handleSync,
         handleRestoreSnapshot,
     } = useSyncSettings({
@@ -876,6 +970,7 @@ export function SettingsView() {
             .then(showSaved)
             .catch((error) => reportError('Failed to update sync preferences', error));
     }, [syncPreferences, showSaved, updateSettings]);
+    const lastSuccessfulSyncDisplay = syncJournal.lastSuccessAt ? formatTimeAgo(syncJournal.lastSuccessAt, locale) : null;
 
     const CalendarPage = () => {
         const {
@@ -977,6 +1072,10 @@ export function SettingsView() {
                     anthropicThinkingEnabled={anthropicThinkingEnabled}
                     anthropicThinkingOptions={anthropicThinkingOptions}
                     aiApiKey={aiApiKey}
+                    aiHasKey={aiHasKey}
+                    aiProviders={aiProviders}
+                    aiProvidersError={aiProvidersError}
+                    aiKeyless={aiKeyless}
                     speechEnabled={speechEnabled}
                     speechProvider={speechProvider}
                     speechModel={speechModel}
@@ -985,6 +1084,7 @@ export function SettingsView() {
                     speechMode={speechMode}
                     speechFieldStrategy={speechFieldStrategy}
                     speechApiKey={speechApiKey}
+                    speechHasKey={speechHasKey}
                     speechOfflineReady={speechOfflineReady}
                     speechOfflineSize={speechOfflineSize}
                     speechDownloadState={speechDownloadState}
@@ -995,6 +1095,8 @@ export function SettingsView() {
                     onSpeechProviderChange={onSpeechProviderChange}
                     onToggleAnthropicThinking={onToggleAnthropicThinking}
                     onAiApiKeyChange={onAiApiKeyChange}
+                    onSaveAIProviders={onSaveAIProviders}
+                    onChooseLocalModel={onChooseLocalModel}
                     onSpeechApiKeyChange={onSpeechApiKeyChange}
                     onDownloadWhisperModel={onDownloadWhisperModel}
                     onDeleteWhisperModel={onDeleteWhisperModel}
@@ -1041,6 +1143,9 @@ export function SettingsView() {
                     onSyncPathChange={setSyncPath}
                     onSaveSyncPath={handleSaveSyncPath}
                     onBrowseSyncPath={handleChangeSyncLocation}
+                    syncFileLayout={syncFileLayout}
+                    syncFileLayoutBusy={syncFileLayoutBusy}
+                    onSetSyncFileLayout={handleSetSyncFileLayout}
                     webdavUrl={webdavUrl}
                     webdavUsername={webdavUsername}
                     webdavPassword={webdavPassword}
@@ -1052,6 +1157,7 @@ export function SettingsView() {
                     onSaveWebDav={handleSaveWebDav}
                     cloudUrl={cloudUrl}
                     cloudToken={cloudToken}
+                    cloudHasToken={cloudHasToken}
                     cloudProvider={cloudProvider}
                     dropboxAppKey={dropboxAppKey}
                     dropboxConfigured={dropboxConfigured}
@@ -1066,7 +1172,67 @@ export function SettingsView() {
                     onConnectDropbox={handleConnectDropbox}
                     onDisconnectDropbox={handleDisconnectDropbox}
                     onTestDropboxConnection={handleTestDropboxConnection}
+                    s3Endpoint={s3Endpoint}
+                    s3Bucket={s3Bucket}
+                    s3Prefix={s3Prefix}
+                    s3Region={s3Region}
+                    s3AccessKeyId={s3AccessKeyId}
+                    s3SecretKey={s3SecretKey}
+                    s3HasSecretKey={s3HasSecretKey}
+                    s3Busy={s3Busy}
+                    s3TestState={s3TestState}
+                    onS3EndpointChange={setS3Endpoint}
+                    onS3BucketChange={setS3Bucket}
+                    onS3PrefixChange={setS3Prefix}
+                    onS3RegionChange={setS3Region}
+                    onS3AccessKeyIdChange={setS3AccessKeyId}
+                    onS3SecretKeyChange={setS3SecretKey}
+                    onSaveS3={handleSaveS3}
+                    onTestS3Connection={handleTestS3Connection}
+                    sftpHost={sftpHost}
+                    sftpPort={sftpPort}
+                    sftpUsername={sftpUsername}
+                    sftpRemotePath={sftpRemotePath}
+                    sftpKeyPath={sftpKeyPath}
+                    sftpKeyPassphrase={sftpKeyPassphrase}
+                    sftpHasKeyPassphrase={sftpHasKeyPassphrase}
+                    sftpBusy={sftpBusy}
+                    sftpTestState={sftpTestState}
+                    onSftpHostChange={setSftpHost}
+                    onSftpPortChange={setSftpPort}
+                    onSftpUsernameChange={setSftpUsername}
+                    onSftpRemotePathChange={setSftpRemotePath}
+                    onSftpKeyPathChange={setSftpKeyPath}
+                    onSftpKeyPassphraseChange={setSftpKeyPassphrase}
+                    onBrowseSftpKey={handleChooseSftpKey}
+                    onSaveSftp={() => handleSaveSftp()}
+                    onTestSftpConnection={handleTestSftpConnection}
+                    gitRepoPath={gitRepoPath}
+                    gitRemote={gitRemote}
+                    gitBranch={gitBranch}
+                    gitBusy={gitBusy}
+                    gitTestState={gitTestState}
+                    onGitRepoPathChange={setGitRepoPath}
+                    onGitRemoteChange={setGitRemote}
+                    onGitBranchChange={setGitBranch}
+                    onBrowseGitRepo={handleChooseGitRepo}
+                    onSaveGit={() => handleSaveGit()}
+                    onTestGitConnection={handleTestGitConnection}
                     onSyncNow={handleSync}
+                    syncIntervalMinutes={syncIntervalMinutes}
+                    onSetSyncIntervalMinutes={handleSetSyncIntervalMinutes}
+                    syncPreview={syncPreview}
+                    syncPreviewBusy={syncPreviewBusy}
+                    onPreviewSync={handlePreviewSync}
+                    syncEncryption={syncEncryption}
+                    syncEncryptionPassphrase={syncEncryptionPassphrase}
+                    syncEncryptionPassphraseConfirm={syncEncryptionPassphraseConfirm}
+                    syncEncryptionBusy={syncEncryptionBusy}
+                    onSyncEncryptionPassphraseChange={setSyncEncryptionPassphrase}
+                    onSyncEncryptionPassphraseConfirmChange={setSyncEncryptionPassphraseConfirm}
+                    onEnableSyncEncryption={handleEnableSyncEncryption}
+                    onRotateSyncEncryptionKey={handleRotateSyncEncryptionKey}
+                    onDisableSyncEncryption={handleDisableSyncEncryption}
                     isSyncing={isSyncing}
                     syncQueued={syncQueued}
                     syncLastResult={syncLastResult}
@@ -1078,6 +1244,8 @@ export function SettingsView() {
                     lastSyncStatus={lastSyncStatus}
                     lastSyncStats={lastSyncStats}
                     lastSyncHistory={lastSyncHistory}
+                    lastSuccessfulSyncDisplay={lastSuccessfulSyncDisplay}
+                    syncJournalEntries={syncJournal.entries}
                     conflictCount={conflictCount}
                     lastSyncError={settings?.lastSyncError}
                     attachmentsLastCleanupDisplay={attachmentsLastCleanupDisplay}
@@ -1087,6 +1255,17 @@ export function SettingsView() {
                     isLoadingSnapshots={isLoadingSnapshots}
                     isRestoringSnapshot={isRestoringSnapshot}
                     onRestoreSnapshot={handleRestoreSnapshot}
+                    secretVault={secretVault}
+                    managedConfigKeys={managedConfigKeys}
+                    secretVaultPassphrase={secretVaultPassphrase}
+                    secretVaultPassphraseConfirm={secretVaultPassphraseConfirm}
+                    secretVaultBusy={secretVaultBusy}
+                    onSecretVaultPassphraseChange={setSecretVaultPassphrase}
+                    onSecretVaultPassphraseConfirmChange={setSecretVaultPassphraseConfirm}
+                    onUnlockSecretVault={handleUnlockSecretVault}
+                    onCreateSecretVault={handleCreateSecretVault}
+                    onLockSecretVault={handleLockSecretVault}
+                    onAllowPlaintextSecrets={handleAllowPlaintextSecrets}
                 />
             );
         }
@@ -1,7 +1,7 @@
 import { useCallback, useEffect, useRef, useState } from 'react';
 import type { AppData } from '@mindwtr/core';
 import { createAIProvider, type AIProviderId } from '@mindwtr/core';
-import { buildCopilotConfig, isAIKeyRequired, loadAIKey } from '../../../lib/ai-config';
+import { buildCopilotConfig, hasAIKey, isAIKeyRequired, loadAIKey } from '../../../lib/ai-config';
 
 type CopilotSuggestion = { context?: string; tags?: string[] };
 
@@ -17,6 +17,7 @@ export function useListCopilot({ settings, newTaskTitle, allContexts, allTags }:
     const aiProvider = (settings?.ai?.provider ?? 'openai') as AIProviderId;
     const keyRequired = isAIKeyRequired(settings);
     const [aiKey, setAiKey] = useState('');
+    const [aiHasKey, setAiHasKey] = useState(false);
     const [copilotSuggestion, setCopilotSuggestion] = useState<CopilotSuggestion | null>(null);
     const [copilotApplied, setCopilotApplied] = useState(false);
     const [copilotContext, setCopilotContext] = useState<string | null>(null);
@@ -25,12 +26,16 @@ export function useListCopilot({ settings, newTaskTitle, allContexts, allTags }:
 
     useEffect(() => {
         let active = true;
-        loadAIKey(aiProvider)
-            .then((key) => {
-                if (active) setAiKey(key);
+        Promise.all([loadAIKey(aiProvider), hasAIKey(aiProvider)])
+            .then(([key, hasKey]) => {
+                if (!active) return;
+                setAiKey(key);
+                setAiHasKey(hasKey);
             })
             .catch(() => {
-                if (active) setAiKey('');
+                if (!active) return;
+                setAiKey('');
+                setAiHasKey(false);
             });
         return () => {
             active = false;
@@ -38,7 +43,7 @@ export function useListCopilot({ settings, newTaskTitle, allContexts, allTags }:
     }, [aiProvider]);
 
     useEffect(() => {
-        if (!aiEnabled || (keyRequired && !aiKey)) {
+        if (!aiEnabled || (keyRequired && !aiHasKey)) {
             setCopilotSuggestion(null);
             return;
         }
@@ -76,7 +81,7 @@ export function useListCopilot({ settings, newTaskTitle, allContexts, allTags }:
                 copilotAbortRef.current = null;
             }
         };
-    }, [aiEnabled, aiKey, allContexts, allTags, keyRequired, newTaskTitle, settings]);
+    }, [aiEnabled, aiHasKey, aiKey, allContexts, allTags, keyRequired, newTaskTitle, settings]);
 
     const applyCopilotSuggestion = useCallback((suggestion: CopilotSuggestion | null) => {
         if (!suggestion) return;
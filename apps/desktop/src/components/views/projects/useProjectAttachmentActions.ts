@@ -4,6 +4,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { size } from '@tauri-apps/plugin-fs';
 import { isTauriRuntime } from '../../../lib/runtime';
 import { logWarn } from '../../../lib/app-log';
+import { SyncService } from '../../../lib/sync-service';
 
 type UseProjectAttachmentActionsParams = {
     t: (key: string) => string;
@@ -27,11 +28,22 @@ export function useProjectAttachmentActions({
     }, [selectedProject?.id]);
 
     const openAttachment = useCallback(async (attachment: Attachment) => {
-        const hasScheme = /^[a-z][a-z0-9+.-]*:\/\//i.test(attachment.uri);
-        const normalized = hasScheme ? attachment.uri : `file://${attachment.uri}`;
+        let uri = attachment.uri;
+        if (isTauriRuntime() && attachment.kind === 'file') {
+            setAttachmentError(null);
+            try {
+                uri = await SyncService.ensureAttachmentAvailable(attachment);
+            } catch (error) {
+                const message = error instanceof Error ? error.message : String(error);
+                setAttachmentError(message || t('attachments.fileNotSupported'));
+                return;
+            }
+        }
+        const hasScheme = /^[a-z][a-z0-9+.-]*:\/\//i.test(uri);
+        const normalized = hasScheme ? uri : `file://${uri}`;
         if (isTauriRuntime()) {
             try {
-                await invoke('open_path', { path: attachment.uri });
+                await invoke('open_path', { path: uri });
                 return;
             } catch (error) {
                 void logWarn('Failed to open attachment', {
@@ -41,7 +53,7 @@ export function useProjectAttachmentActions({
             }
         }
         window.open(normalized, '_blank');
-    }, []);
+    }, [t]);
 
     const addProjectFileAttachment = useCallback(async () => {
         if (!selectedProject) return;
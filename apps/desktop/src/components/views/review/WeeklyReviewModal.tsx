@@ -21,7 +21,7 @@ import { TaskItem } from '../../TaskItem';
 import { PromptModal } from '../../PromptModal';
 import { cn } from '../../../lib/utils';
 import { useLanguage } from '../../../contexts/language-context';
-import { buildAIConfig, isAIKeyRequired, loadAIKey } from '../../../lib/ai-config';
+import { buildAIConfig, hasAIKey, isAIKeyRequired, loadAIKey } from '../../../lib/ai-config';
 import { fetchExternalCalendarEvents } from '../../../lib/external-calendar-events';
 
 type ReviewStep = 'inbox' | 'ai' | 'calendar' | 'waiting' | 'contexts' | 'projects' | 'someday' | 'completed';
@@ -271,7 +271,7 @@ export function WeeklyReviewGuideModal({ onClose }: WeeklyReviewGuideModalProps)
             return;
         }
         const apiKey = await loadAIKey(aiProvider);
-        if (isAIKeyRequired(settings) && !apiKey) {
+        if (isAIKeyRequired(settings) && !(await hasAIKey(aiProvider))) {
             setAiError(t('ai.missingKeyBody'));
             return;
         }
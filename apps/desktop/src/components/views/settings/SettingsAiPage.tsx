@@ -1,4 +1,4 @@
-import type { AIProviderId, AIReasoningEffort, AppData } from '@mindwtr/core';
+import type { AIProviderDefinition, AIProviderId, AIProviderInfo, AIProviderKind, AIReasoningEffort, AppData } from '@mindwtr/core';
 
 import { useState } from 'react';
 import { cn } from '../../../lib/utils';
@@ -35,6 +35,22 @@ type Labels = {
     aiThinkingHigh: string;
     aiApiKey: string;
     aiApiKeyHint: string;
+    aiCustomProviders: string;
+    aiCustomProvidersHint: string;
+    aiProviderId: string;
+    aiProviderName: string;
+    aiProviderKind: string;
+    aiProviderKindOpenAI: string;
+    aiProviderKindLocal: string;
+    aiProviderModelFile: string;
+    aiProviderChooseModel: string;
+    aiLocalNoKey: string;
+    aiProviderBaseUrl: string;
+    aiProviderDefaultModel: string;
+    aiProviderAdd: string;
+    aiProviderRemove: string;
+    aiBaseUrlMoved: string;
+    aiBaseUrlMove: string;
     speechTitle: string;
     speechDesc: string;
     speechEnable: string;
@@ -65,6 +81,16 @@ type Labels = {
 
 type ThinkingOption = { value: number; label: string };
 
+const EMPTY_PROVIDER_DRAFT: AIProviderDefinition = {
+    id: '',
+    name: '',
+    kind: 'openai-compatible',
+    baseUrl: '',
+    defaultModel: '',
+};
+
+const toProviderDefinition = ({ builtIn: _builtIn, configured: _configured, hasKey: _hasKey, ...definition }: AIProviderInfo): AIProviderDefinition => definition;
+
 type SettingsAiPageProps = {
     t: Labels;
     aiEnabled: boolean;
@@ -79,6 +105,11 @@ type SettingsAiPageProps = {
     anthropicThinkingEnabled: boolean;
     anthropicThinkingOptions: ThinkingOption[];
     aiApiKey: string;
+    aiHasKey: boolean;
+    aiProviders: AIProviderInfo[];
+    aiProvidersError: string | null;
+    /** The selected provider runs on this device and takes no API key. */
+    aiKeyless: boolean;
     speechEnabled: boolean;
     speechProvider: 'openai' | 'gemini' | 'whisper';
     speechModel: string;
@@ -87,6 +118,7 @@ type SettingsAiPageProps = {
     speechMode: 'smart_parse' | 'transcribe_only';
     speechFieldStrategy: 'smart' | 'title_only' | 'description_only';
     speechApiKey: string;
+    speechHasKey: boolean;
     speechOfflineReady: boolean;
     speechOfflineSize: number | null;
     speechDownloadState: 'idle' | 'downloading' | 'success' | 'error';
@@ -97,6 +129,10 @@ type SettingsAiPageProps = {
     onSpeechProviderChange: (provider: 'openai' | 'gemini' | 'whisper') => void;
     onToggleAnthropicThinking: () => void;
     onAiApiKeyChange: (value: string) => void;
+    /** Resolves false when the backend rejected the list; the error is in `aiProvidersError`. */
+    onSaveAIProviders: (providers: AIProviderDefinition[]) => Promise<boolean>;
+    /** Opens a file picker for a GGUF model; resolves null when cancelled. */
+    onChooseLocalModel: () => Promise<string | null>;
     onSpeechApiKeyChange: (value: string) => void;
     onDownloadWhisperModel: () => void;
     onDeleteWhisperModel: () => void;
@@ -116,6 +152,10 @@ export function SettingsAiPage({
     anthropicThinkingEnabled,
     anthropicThinkingOptions,
     aiApiKey,
+    aiHasKey,
+    aiProviders,
+    aiProvidersError,
+    aiKeyless,
     speechEnabled,
     speechProvider,
     speechModel,
@@ -124,6 +164,7 @@ export function SettingsAiPage({
     speechMode,
     speechFieldStrategy,
     speechApiKey,
+    speechHasKey,
     speechOfflineReady,
     speechOfflineSize,
     speechDownloadState,
@@ -134,6 +175,8 @@ export function SettingsAiPage({
     onSpeechProviderChange,
     onToggleAnthropicThinking,
     onAiApiKeyChange,
+    onSaveAIProviders,
+    onChooseLocalModel,
     onSpeechApiKeyChange,
     onDownloadWhisperModel,
     onDeleteWhisperModel,
@@ -141,11 +184,59 @@ export function SettingsAiPage({
     const [aiOpen, setAiOpen] = useState(false);
     const [speechOpen, setSpeechOpen] = useState(false);
     const [showAiConsentModal, setShowAiConsentModal] = useState(false);
-    const selectedProviderLabel = aiProvider === 'gemini'
-        ? t.aiProviderGemini
-        : aiProvider === 'anthropic'
-            ? t.aiProviderAnthropic
-            : t.aiProviderOpenAI;
+    const [draftProvider, setDraftProvider] = useState<AIProviderDefinition>(EMPTY_PROVIDER_DRAFT);
+    const customProviders = aiProviders.filter((provider) => !provider.builtIn);
+    const configuredProviders = aiProviders.filter((provider) => provider.configured);
+    const selectedRegistryProvider = customProviders.find((provider) => provider.id === aiProvider);
+    const selectedProviderLabel = selectedRegistryProvider
+        ? selectedRegistryProvider.name
+        : aiProvider === 'gemini'
+            ? t.aiProviderGemini
+            : aiProvider === 'anthropic'
+                ? t.aiProviderAnthropic
+                : t.aiProviderOpenAI;
+    const draftIsLocal = draftProvider.kind === 'local';
+    const draftReady = draftProvider.id.trim().length > 0
+        && (draftIsLocal ? Boolean(draftProvider.modelPath?.trim()) : draftProvider.baseUrl.trim().length > 0);
+    const handleAddProvider = async () => {
+        const saved = await onSaveAIProviders([
+            ...configuredProviders.map(toProviderDefinition),
+            {
+                ...draftProvider,
+                baseUrl: draftIsLocal ? '' : draftProvider.baseUrl,
+                modelPath: draftIsLocal ? draftProvider.modelPath : null,
+                defaultModel: draftProvider.defaultModel || null,
+            },
+        ]);
+        if (saved) setDraftProvider(EMPTY_PROVIDER_DRAFT);
+    };
+    // The desktop backend only calls registry endpoints, each with its own key, so an old
+    // per-request base URL becomes a provider instead.
+    const handleMoveBaseUrl = async () => {
+        const ids = new Set(aiProviders.map((provider) => provider.id));
+        let id = 'openai-custom';
+        for (let index = 2; ids.has(id); index += 1) id = `openai-custom-${index}`;
+        const saved = await onSaveAIProviders([
+            ...configuredProviders.map(toProviderDefinition),
+            {
+                id,
+                name: t.aiProviderKindOpenAI,
+                kind: 'openai-compatible',
+                baseUrl: aiBaseUrl.trim().replace(/\/chat\/completions\/?$/i, ''),
+                defaultModel: aiModel || null,
+            },
+        ]);
+        if (!saved) return;
+        onUpdateAISettings({ baseUrl: undefined });
+        onProviderChange(id);
+    };
+    const handleChooseModel = async () => {
+        const modelPath = await onChooseLocalModel();
+        if (modelPath) setDraftProvider((prev) => ({ ...prev, modelPath }));
+    };
+    const handleRemoveProvider = (id: string) => {
+        void onSaveAIProviders(configuredProviders.filter((provider) => provider.id !== id).map(toProviderDefinition));
+    };
     const aiConsentDescription = t.aiConsentDescription.replace('{provider}', selectedProviderLabel);
     const handleAiToggle = () => {
         if (aiEnabled) {
@@ -204,6 +295,9 @@ export function SettingsAiPage({
                                     <option value="openai">{t.aiProviderOpenAI}</option>
                                     <option value="gemini">{t.aiProviderGemini}</option>
                                     <option value="anthropic">{t.aiProviderAnthropic}</option>
+                                    {customProviders.map((provider) => (
+                                        <option key={provider.id} value={provider.id}>{provider.name}</option>
+                                    ))}
                                 </select>
                             </div>
 
@@ -262,7 +356,22 @@ export function SettingsAiPage({
                                 </div>
                             )}
 
-                            {aiProvider === 'openai' && (
+                            {aiProvider === 'openai' && aiProviders.length > 0 && aiBaseUrl.trim() && (
+                                <div className="flex items-center justify-between gap-4 rounded-lg border border-border bg-muted/30 p-3">
+                                    <div className="text-xs text-muted-foreground break-all">
+                                        {t.aiBaseUrlMoved.replace('{url}', aiBaseUrl.trim())}
+                                    </div>
+                                    <button
+                                        type="button"
+                                        onClick={() => void handleMoveBaseUrl()}
+                                        className="px-3 py-1.5 rounded-md text-sm font-medium bg-primary text-primary-foreground hover:bg-primary/90 whitespace-nowrap"
+                                    >
+                                        {t.aiBaseUrlMove}
+                                    </button>
+                                </div>
+                            )}
+
+                            {aiProvider === 'openai' && aiProviders.length === 0 && (
                                 <div className="space-y-2 rounded-lg border border-border bg-muted/30 p-3">
                                     <div className="text-sm font-medium">{t.aiBaseUrl}</div>
                                     <input
@@ -348,15 +457,137 @@ export function SettingsAiPage({
 
                         <div className="border-t border-border p-4 space-y-2">
                             <div className="text-sm font-medium">{t.aiApiKey}</div>
-                            <input
-                                type="password"
-                                value={aiApiKey}
-                                onChange={(e) => onAiApiKeyChange(e.target.value)}
-                                placeholder={t.aiApiKey}
-                                className="w-full text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 focus:outline-none focus:ring-2 focus:ring-primary/40"
-                            />
-                            <div className="text-xs text-muted-foreground">{t.aiApiKeyHint}</div>
+                            {aiKeyless ? (
+                                <div className="text-xs text-muted-foreground">{t.aiLocalNoKey}</div>
+                            ) : (
+                                <>
+                                    <input
+                                        type="password"
+                                        value={aiApiKey}
+                                        onChange={(e) => onAiApiKeyChange(e.target.value)}
+                                        placeholder={aiHasKey && !aiApiKey ? '••••••••' : t.aiApiKey}
+                                        className="w-full text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 focus:outline-none focus:ring-2 focus:ring-primary/40"
+                                    />
+                                    <div className="text-xs text-muted-foreground">{t.aiApiKeyHint}</div>
+                                </>
+                            )}
                         </div>
+
+                        {aiProviders.length > 0 && (
+                            <div className="border-t border-border p-4 space-y-3">
+                                <div>
+                                    <div className="text-sm font-medium">{t.aiCustomProviders}</div>
+                                    <div className="text-xs text-muted-foreground">{t.aiCustomProvidersHint}</div>
+                                </div>
+                                {configuredProviders.map((provider) => (
+                                    <div key={provider.id} className="flex items-center justify-between gap-4">
+                                        <div className="min-w-0">
+                                            <div className="text-sm font-medium truncate">
+                                                {provider.name} <span className="text-xs font-mono text-muted-foreground">{provider.id}</span>
+                                            </div>
+                                            <div className="text-xs text-muted-foreground truncate">
+                                                {provider.kind === 'local' ? provider.modelPath : provider.baseUrl}
+                                            </div>
+                                        </div>
+                                        <button
+                                            type="button"
+                                            onClick={() => handleRemoveProvider(provider.id)}
+                                            className="px-3 py-1.5 rounded-md text-sm font-medium bg-muted/50 text-muted-foreground border border-border hover:bg-muted hover:text-foreground"
+                                        >
+                                            {t.aiProviderRemove}
+                                        </button>
+                                    </div>
+                                ))}
+                                <div className="grid gap-2 sm:grid-cols-2">
+                                    <input
+                                        type="text"
+                                        value={draftProvider.id}
+                                        onChange={(e) => setDraftProvider((prev) => ({ ...prev, id: e.target.value.toLowerCase() }))}
+                                        placeholder={t.aiProviderId}
+                                        aria-label={t.aiProviderId}
+                                        className="text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 focus:outline-none focus:ring-2 focus:ring-primary/40"
+                                        autoCapitalize="off"
+                                        autoCorrect="off"
+                                        spellCheck={false}
+                                    />
+                                    <input
+                                        type="text"
+                                        value={draftProvider.name}
+                                        onChange={(e) => setDraftProvider((prev) => ({ ...prev, name: e.target.value }))}
+                                        placeholder={t.aiProviderName}
+                                        aria-label={t.aiProviderName}
+                                        className="text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 focus:outline-none focus:ring-2 focus:ring-primary/40"
+                                    />
+                                    <select
+                                        value={draftProvider.kind}
+                                        onChange={(e) => setDraftProvider((prev) => ({ ...prev, kind: e.target.value as AIProviderKind }))}
+                                        aria-label={t.aiProviderKind}
+                                        className="text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 hover:bg-muted focus:outline-none focus:ring-2 focus:ring-primary/40"
+                                    >
+                                        <option value="openai-compatible">{t.aiProviderKindOpenAI}</option>
+                                        <option value="anthropic">{t.aiProviderAnthropic}</option>
+                                        <option value="gemini">{t.aiProviderGemini}</option>
+                                        <option value="local">{t.aiProviderKindLocal}</option>
+                                    </select>
+                                    <input
+                                        type="text"
+                                        value={draftProvider.defaultModel ?? ''}
+                                        onChange={(e) => setDraftProvider((prev) => ({ ...prev, defaultModel: e.target.value }))}
+                                        placeholder={t.aiProviderDefaultModel}
+                                        aria-label={t.aiProviderDefaultModel}
+                                        className="text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 focus:outline-none focus:ring-2 focus:ring-primary/40"
+                                        autoCapitalize="off"
+                                        autoCorrect="off"
+                                        spellCheck={false}
+                                    />
+                                    {draftIsLocal ? (
+                                        <div className="sm:col-span-2 flex gap-2">
+                                            <input
+                                                type="text"
+                                                value={draftProvider.modelPath ?? ''}
+                                                onChange={(e) => setDraftProvider((prev) => ({ ...prev, modelPath: e.target.value }))}
+                                                placeholder={`${t.aiProviderModelFile} (.gguf)`}
+                                                aria-label={t.aiProviderModelFile}
+                                                className="flex-1 min-w-0 text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 focus:outline-none focus:ring-2 focus:ring-primary/40"
+                                                autoCapitalize="off"
+                                                autoCorrect="off"
+                                                spellCheck={false}
+                                            />
+                                            <button
+                                                type="button"
+                                                onClick={() => void handleChooseModel()}
+                                                className="px-3 py-1.5 rounded-md text-sm font-medium bg-muted/50 text-muted-foreground border border-border hover:bg-muted hover:text-foreground whitespace-nowrap"
+                                            >
+                                                {t.aiProviderChooseModel}
+                                            </button>
+                                        </div>
+                                    ) : (
+                                        <input
+                                            type="text"
+                                            value={draftProvider.baseUrl}
+                                            onChange={(e) => setDraftProvider((prev) => ({ ...prev, baseUrl: e.target.value }))}
+                                            placeholder={`${t.aiProviderBaseUrl} (http://localhost:11434/v1)`}
+                                            aria-label={t.aiProviderBaseUrl}
+                                            className="sm:col-span-2 text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 focus:outline-none focus:ring-2 focus:ring-primary/40"
+                                            autoCapitalize="off"
+                                            autoCorrect="off"
+                                            spellCheck={false}
+                                        />
+                                    )}
+                                </div>
+                                {aiProvidersError && <div className="text-xs text-destructive break-all">{aiProvidersError}</div>}
+                                <div className="flex justify-end">
+                                    <button
+                                        type="button"
+                                        onClick={() => void handleAddProvider()}
+                                        disabled={!draftReady}
+                                        className="px-4 py-2 bg-primary text-primary-foreground rounded-md text-sm font-medium hover:bg-primary/90 whitespace-nowrap disabled:bg-muted disabled:text-muted-foreground disabled:cursor-not-allowed"
+                                    >
+                                        {t.aiProviderAdd}
+                                    </button>
+                                </div>
+                            </div>
+                        )}
                     </>
                 )}
             </div>
@@ -466,7 +697,7 @@ export function SettingsAiPage({
                                     type="password"
                                     value={speechApiKey}
                                     onChange={(e) => onSpeechApiKeyChange(e.target.value)}
-                                    placeholder={t.aiApiKey}
+                                    placeholder={speechHasKey && !speechApiKey ? '••••••••' : t.aiApiKey}
                                     className="w-full text-sm bg-muted/50 text-foreground border border-border rounded px-2 py-2 focus:outline-none focus:ring-2 focus:ring-primary/40"
                                 />
                                 <div className="text-xs text-muted-foreground">{t.aiApiKeyHint}</div>
@@ -1,5 +1,5 @@
 import { useState } from 'react';
-import type { AppData } from '@mindwtr/core';
+import type { AppData, SyncPreview } from '@mindwtr/core';
 import { safeFormatDate } from '@mindwtr/core';
 import { Info, RefreshCw, Trash2 } from 'lucide-react';
 
@@ -20,6 +20,9 @@ type Labels = {
     syncBackendFile: string;
     syncBackendWebdav: string;
     syncBackendCloud: string;
+    syncBackendS3: string;
+    syncBackendGit: string;
+    syncBackendSftp: string;
     syncPreferences: string;
     syncPreferencesDesc: string;
     syncPreferenceAppearance: string;
@@ -54,6 +57,63 @@ type Labels = {
     dropboxTest: string;
     dropboxTestReachable: string;
     dropboxTestFailed: string;
+    s3Endpoint: string;
+    s3EndpointHint: string;
+    s3Bucket: string;
+    s3Prefix: string;
+    s3Region: string;
+    s3AccessKeyId: string;
+    s3SecretKey: string;
+    s3Save: string;
+    s3Test: string;
+    s3TestReachable: string;
+    s3TestFailed: string;
+    sftpHost: string;
+    sftpPort: string;
+    sftpUsername: string;
+    sftpRemotePath: string;
+    sftpRemotePathHint: string;
+    sftpKeyPath: string;
+    sftpKeyPathHint: string;
+    sftpKeyPassphrase: string;
+    sftpSave: string;
+    gitRepoPath: string;
+    gitRepoHint: string;
+    gitRemote: string;
+    gitBranch: string;
+    gitBranchHint: string;
+    gitSave: string;
+    gitTest: string;
+    syncFileLayout: string;
+    syncFileLayoutSingle: string;
+    syncFileLayoutEntities: string;
+    syncFileLayoutHint: string;
+    syncInterval: string;
+    syncIntervalOff: string;
+    syncIntervalMinutes: string;
+    syncIntervalHint: string;
+    syncPreview: string;
+    syncPreviewing: string;
+    syncPreviewHint: string;
+    syncPreviewLocal: string;
+    syncPreviewRemote: string;
+    syncPreviewCounts: string;
+    syncPreviewConflicts: string;
+    syncPreviewNoChanges: string;
+    syncEncryption: string;
+    syncEncryptionHint: string;
+    syncEncryptionOn: string;
+    syncEncryptionOff: string;
+    syncEncryptionRotationPending: string;
+    syncEncryptionPassphrase: string;
+    syncEncryptionPassphraseConfirm: string;
+    syncEncryptionMismatch: string;
+    syncEncryptionEnable: string;
+    syncEncryptionEnableConfirm: string;
+    syncEncryptionRotate: string;
+    syncEncryptionRotateConfirm: string;
+    syncEncryptionDisable: string;
+    syncEncryptionDisableConfirm: string;
     syncNow: string;
     syncing: string;
     syncQueued: string;
@@ -66,6 +126,22 @@ type Labels = {
     lastSyncAdjusted: string;
     lastSyncConflictIds: string;
     syncHistory: string;
+    lastSuccessfulSync: string;
+    syncJournal: string;
+    syncJournalChanges: string;
+    secretVault: string;
+    secretVaultHint: string;
+    secretVaultLocked: string;
+    secretVaultUnlocked: string;
+    secretVaultMissing: string;
+    secretVaultUnlock: string;
+    secretVaultLock: string;
+    secretVaultCreate: string;
+    secretVaultCreateConfirm: string;
+    secretVaultPlaintext: string;
+    secretVaultPlaintextActive: string;
+    managedSettings: string;
+    managedSettingsHint: string;
     recoverySnapshots: string;
     recoverySnapshotsDesc: string;
     recoverySnapshotsLoading: string;
@@ -82,9 +158,39 @@ type Labels = {
     attachmentsCleanupRunning: string;
 };
 
-type SyncBackend = 'off' | 'file' | 'webdav' | 'cloud';
+type SyncBackend = 'off' | 'file' | 'webdav' | 'cloud' | 's3' | 'git' | 'sftp';
+type SyncFileLayout = 'single' | 'entities';
+type SyncEncryptionStatus = {
+    enabled: boolean;
+    keyId: string | null;
+    rotationPending: boolean;
+};
+type SyncEncryptionAction = 'enable' | 'rotate' | 'disable';
+type SyncJournalEntry = {
+    id?: number;
+    startedAt: string;
+    finishedAt?: string | null;
+    backend: string;
+    bytes: number;
+    added: number;
+    updated: number;
+    deleted: number;
+    conflicts: number;
+    error?: string | null;
+};
 type CloudProvider = 'selfhosted' | 'dropbox';
 type DropboxTestState = 'idle' | 'success' | 'error';
+type S3TestState = 'idle' | 'success' | 'error';
+type GitTestState = 'idle' | 'success' | 'error';
+type SftpTestState = 'idle' | 'success' | 'error';
+
+type SecretVaultStatus = {
+    exists: boolean;
+    unlocked: boolean;
+    keyringAvailable: boolean;
+    storedSecrets: number;
+    plaintextAllowed: boolean;
+};
 
 type SettingsSyncPageProps = {
     t: Labels;
@@ -101,6 +207,9 @@ type SettingsSyncPageProps = {
     onSyncPathChange: (value: string) => void;
     onSaveSyncPath: () => Promise<void> | void;
     onBrowseSyncPath: () => void;
+    syncFileLayout: SyncFileLayout;
+    syncFileLayoutBusy: boolean;
+    onSetSyncFileLayout: (layout: SyncFileLayout) => void;
     webdavUrl: string;
     webdavUsername: string;
     webdavPassword: string;
@@ -112,6 +221,7 @@ type SettingsSyncPageProps = {
     onSaveWebDav: () => Promise<void> | void;
     cloudUrl: string;
     cloudToken: string;
+    cloudHasToken: boolean;
     cloudProvider: CloudProvider;
     dropboxAppKey: string;
     dropboxConfigured: boolean;
@@ -126,7 +236,67 @@ type SettingsSyncPageProps = {
     onConnectDropbox: () => Promise<void> | void;
     onDisconnectDropbox: () => Promise<void> | void;
     onTestDropboxConnection: () => Promise<void> | void;
+    s3Endpoint: string;
+    s3Bucket: string;
+    s3Prefix: string;
+    s3Region: string;
+    s3AccessKeyId: string;
+    s3SecretKey: string;
+    s3HasSecretKey: boolean;
+    s3Busy: boolean;
+    s3TestState: S3TestState;
+    onS3EndpointChange: (value: string) => void;
+    onS3BucketChange: (value: string) => void;
+    onS3PrefixChange: (value: string) => void;
+    onS3RegionChange: (value: string) => void;
+    onS3AccessKeyIdChange: (value: string) => void;
+    onS3SecretKeyChange: (value: string) => void;
+    onSaveS3: () => Promise<unknown> | void;
+    onTestS3Connection: () => Promise<void> | void;
+    sftpHost: string;
+    sftpPort: string;
+    sftpUsername: string;
+    sftpRemotePath: string;
+    sftpKeyPath: string;
+    sftpKeyPassphrase: string;
+    sftpHasKeyPassphrase: boolean;
+    sftpBusy: boolean;
+    sftpTestState: SftpTestState;
+    onSftpHostChange: (value: string) => void;
+    onSftpPortChange: (value: string) => void;
+    onSftpUsernameChange: (value: string) => void;
+    onSftpRemotePathChange: (value: string) => void;
+    onSftpKeyPathChange: (value: string) => void;
+    onSftpKeyPassphraseChange: (value: string) => void;
+    onBrowseSftpKey: () => Promise<void> | void;
+    onSaveSftp: () => Promise<unknown> | void;
+    onTestSftpConnection: () => Promise<void> | void;
+    gitRepoPath: string;
+    gitRemote: string;
+    gitBranch: string;
+    gitBusy: boolean;
+    gitTestState: GitTestState;
+    onGitRepoPathChange: (value: string) => void;
+    onGitRemoteChange: (value: string) => void;
+    onGitBranchChange: (value: string) => void;
+    onBrowseGitRepo: () => Promise<void> | void;
+    onSaveGit: () => Promise<unknown> | void;
+    onTestGitConnection: () => Promise<void> | void;
     onSyncNow: () => Promise<void> | void;
+    syncIntervalMinutes: number;
+    onSetSyncIntervalMinutes: (minutes: number) => void;
+    syncPreview: SyncPreview | null;
+    syncPreviewBusy: boolean;
+    onPreviewSync: () => Promise<void> | void;
+    syncEncryption: SyncEncryptionStatus;
+    syncEncryptionPassphrase: string;
+    syncEncryptionPassphraseConfirm: string;
+    syncEncryptionBusy: boolean;
+    onSyncEncryptionPassphraseChange: (value: string) => void;
+    onSyncEncryptionPassphraseConfirmChange: (value: string) => void;
+    onEnableSyncEncryption: () => Promise<void> | void;
+    onRotateSyncEncryptionKey: () => Promise<void> | void;
+    onDisableSyncEncryption: () => Promise<void> | void;
     isSyncing: boolean;
     syncQueued: boolean;
     syncLastResult: 'success' | 'error' | null;
@@ -136,6 +306,8 @@ type SettingsSyncPageProps = {
     lastSyncStatus: AppData['settings']['lastSyncStatus'];
     lastSyncStats: AppData['settings']['lastSyncStats'] | null;
     lastSyncHistory: AppData['settings']['lastSyncHistory'] | null;
+    lastSuccessfulSyncDisplay: string | null;
+    syncJournalEntries: SyncJournalEntry[];
     conflictCount: number;
     lastSyncError?: string;
     attachmentsLastCleanupDisplay: string;
@@ -145,8 +317,22 @@ type SettingsSyncPageProps = {
     isLoadingSnapshots: boolean;
     isRestoringSnapshot: boolean;
     onRestoreSnapshot: (snapshotFileName: string) => Promise<boolean | void> | boolean | void;
+    secretVault: SecretVaultStatus | null;
+    managedConfigKeys: Record<string, string>;
+    secretVaultPassphrase: string;
+    secretVaultPassphraseConfirm: string;
+    secretVaultBusy: boolean;
+    onSecretVaultPassphraseChange: (value: string) => void;
+    onSecretVaultPassphraseConfirmChange: (value: string) => void;
+    onUnlockSecretVault: () => Promise<void> | void;
+    onCreateSecretVault: () => Promise<void> | void;
+    onLockSecretVault: () => Promise<void> | void;
+    onAllowPlaintextSecrets: () => Promise<void> | void;
 };
 
+const SYNC_INTERVAL_OPTIONS = [0, 5, 15, 30, 60] as const;
+const SYNC_PREVIEW_ITEM_LIMIT = 8;
+
 const isValidHttpUrl = (value: string): boolean => {
     if (!value.trim()) return false;
     try {
@@ -166,6 +352,13 @@ const formatClockSkew = (ms: number): string => {
     return `${minutes.toFixed(1)} min`;
 };
 
+const formatByteCount = (bytes: number): string => {
+    if (!Number.isFinite(bytes) || bytes <= 0) return '0 B';
+    if (bytes < 1024) return `${bytes} B`;
+    if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+    return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+};
+
 export function SettingsSyncPage({
     t,
     isTauri,
@@ -181,6 +374,9 @@ export function SettingsSyncPage({
     onSyncPathChange,
     onSaveSyncPath,
     onBrowseSyncPath,
+    syncFileLayout,
+    syncFileLayoutBusy,
+    onSetSyncFileLayout,
     webdavUrl,
     webdavUsername,
     webdavPassword,
@@ -192,6 +388,7 @@ export function SettingsSyncPage({
     onSaveWebDav,
     cloudUrl,
     cloudToken,
+    cloudHasToken,
     cloudProvider,
     dropboxAppKey,
     dropboxConfigured,
@@ -206,7 +403,67 @@ export function SettingsSyncPage({
     onConnectDropbox,
     onDisconnectDropbox,
     onTestDropboxConnection,
+    s3Endpoint,
+    s3Bucket,
+    s3Prefix,
+    s3Region,
+    s3AccessKeyId,
+    s3SecretKey,
+    s3HasSecretKey,
+    s3Busy,
+    s3TestState,
+    onS3EndpointChange,
+    onS3BucketChange,
+    onS3PrefixChange,
+    onS3RegionChange,
+    onS3AccessKeyIdChange,
+    onS3SecretKeyChange,
+    onSaveS3,
+    onTestS3Connection,
+    sftpHost,
+    sftpPort,
+    sftpUsername,
+    sftpRemotePath,
+    sftpKeyPath,
+    sftpKeyPassphrase,
+    sftpHasKeyPassphrase,
+    sftpBusy,
+    sftpTestState,
+    onSftpHostChange,
+    onSftpPortChange,
+    onSftpUsernameChange,
+    onSftpRemotePathChange,
+    onSftpKeyPathChange,
+    onSftpKeyPassphraseChange,
+    onBrowseSftpKey,
+    onSaveSftp,
+    onTestSftpConnection,
+    gitRepoPath,
+    gitRemote,
+    gitBranch,
+    gitBusy,
+    gitTestState,
+    onGitRepoPathChange,
+    onGitRemoteChange,
+    onGitBranchChange,
+    onBrowseGitRepo,
+    onSaveGit,
+    onTestGitConnection,
     onSyncNow,
+    syncIntervalMinutes,
+    onSetSyncIntervalMinutes,
+    syncPreview,
+    syncPreviewBusy,
+    onPreviewSync,
+    syncEncryption,
+    syncEncryptionPassphrase,
+    syncEncryptionPassphraseConfirm,
+    syncEncryptionBusy,
+    onSyncEncryptionPassphraseChange,
+    onSyncEncryptionPassphraseConfirmChange,
+    onEnableSyncEncryption,
+    onRotateSyncEncryptionKey,
+    onDisableSyncEncryption,
     isSyncing,
     syncQueued,
     syncLastResult,
@@ -216,6 +473,8 @@ export function SettingsSyncPage({
     lastSyncStatus,
     lastSyncStats,
     lastSyncHistory,
+    lastSuccessfulSyncDisplay,
+    syncJournalEntries,
     conflictCount,
     lastSyncError,
     attachmentsLastCleanupDisplay,
@@ -225,9 +484,22 @@ export function SettingsSyncPage({
     isLoadingSnapshots,
     isRestoringSnapshot,
     onRestoreSnapshot,
+    secretVault,
+    managedConfigKeys,
+    secretVaultPassphrase,
+    secretVaultPassphraseConfirm,
+    secretVaultBusy,
+    onSecretVaultPassphraseChange,
+    onSecretVaultPassphraseConfirmChange,
+    onUnlockSecretVault,
+    onCreateSecretVault,
+    onLockSecretVault,
+    onAllowPlaintextSecrets,
 }: SettingsSyncPageProps) {
     const webdavUrlError = webdavUrl.trim() ? !isValidHttpUrl(webdavUrl.trim()) : false;
     const cloudUrlError = cloudUrl.trim() ? !isValidHttpUrl(cloudUrl.trim()) : false;
+    const s3EndpointError = s3Endpoint.trim() ? !isValidHttpUrl(s3Endpoint.trim()) : false;
+    const sftpPortError = sftpPort.trim() ? !/^\d{1,5}$/.test(sftpPort.trim()) || Number(sftpPort.trim()) < 1 || Number(sftpPort.trim()) > 65535 : false;
     const isSyncTargetValid =
         syncBackend === 'file'
             ? !!syncPath.trim()
@@ -237,7 +509,13 @@ export function SettingsSyncPage({
                     ? (cloudProvider === 'selfhosted'
                         ? !!cloudUrl.trim() && !cloudUrlError
                         : dropboxConfigured && !!dropboxAppKey.trim() && dropboxConnected)
-                    : false;
+                    : syncBackend === 's3'
+                        ? !!s3Bucket.trim() && !s3EndpointError && !!s3AccessKeyId.trim()
+                        : syncBackend === 'git'
+                            ? !!gitRepoPath.trim()
+                            : syncBackend === 'sftp'
+                                ? !!sftpHost.trim() && !!sftpUsername.trim() && !sftpPortError
+                                : false;
     const maxClockSkewMs = Math.max(lastSyncStats?.tasks.maxClockSkewMs ?? 0, lastSyncStats?.projects.maxClockSkewMs ?? 0);
     const timestampAdjustments = (lastSyncStats?.tasks.timestampAdjustments ?? 0) + (lastSyncStats?.projects.timestampAdjustments ?? 0);
     const conflictIds = [
@@ -269,7 +547,24 @@ export function SettingsSyncPage({
     const [syncOptionsOpen, setSyncOptionsOpen] = useState(false);
     const [syncHistoryOpen, setSyncHistoryOpen] = useState(false);
     const [snapshotsOpen, setSnapshotsOpen] = useState(false);
+    const [syncJournalOpen, setSyncJournalOpen] = useState(false);
     const [snapshotToRestore, setSnapshotToRestore] = useState<string | null>(null);
+    const [secretVaultCreateOpen, setSecretVaultCreateOpen] = useState(false);
+    const showSecretVault = isTauri && secretVault !== null && (!secretVault.keyringAvailable || secretVault.exists);
+    const secretVaultMismatch = secretVaultPassphraseConfirm.length > 0
+        && secretVaultPassphrase !== secretVaultPassphraseConfirm;
+    const secretVaultCreateReady = secretVaultPassphrase.length > 0
+        && secretVaultPassphrase === secretVaultPassphraseConfirm;
+    const [encryptionAction, setEncryptionAction] = useState<SyncEncryptionAction | null>(null);
+    const encryptionPassphraseMismatch = syncEncryptionPassphraseConfirm.length > 0
+        && syncEncryptionPassphrase !== syncEncryptionPassphraseConfirm;
+    const encryptionPassphraseReady = syncEncryptionPassphrase.length > 0
+        && syncEncryptionPassphrase === syncEncryptionPassphraseConfirm;
+    const encryptionConfirmCopy: Record<SyncEncryptionAction, { title: string; description: string }> = {
+        enable: { title: t.syncEncryptionEnable, description: t.syncEncryptionEnableConfirm },
+        rotate: { title: t.syncEncryptionRotate, description: t.syncEncryptionRotateConfirm },
+        disable: { title: t.syncEncryptionDisable, description: t.syncEncryptionDisableConfirm },
+    };
     const formatSnapshotLabel = (fileName: string) => {
         const match = fileName.match(/^data\.(\d{4}-\d{2}-\d{2})T(\d{2})-(\d{2})-(\d{2})\.snapshot\.json$/);
         if (!match) return fileName;
@@ -284,6 +579,34 @@ export function SettingsSyncPage({
         return utc.toLocaleString();
     };
 
+    const renderSyncPreviewItems = (items: SyncPreview['conflicts'], marker: string) =>
+        items.slice(0, SYNC_PREVIEW_ITEM_LIMIT).map((item) => (
+            <div key={`${marker}-${item.entity}-${item.id}`} className="truncate text-muted-foreground">
+                {marker} {item.title || item.id} <span className="opacity-70">({item.entity})</span>
+            </div>
+        ));
+
+    const renderSyncPreviewSide = (label: string, changes: SyncPreview['local']) => (
+        <div className="space-y-0.5">
+            <div className="font-medium">
+                {label}:{' '}
+                {t.syncPreviewCounts
+                    .replace('{created}', String(changes.created.length))
+                    .replace('{updated}', String(changes.updated.length))
+                    .replace('{deleted}', String(changes.deleted.length))}
+            </div>
+            {renderSyncPreviewItems(changes.created, '+')}
+            {renderSyncPreviewItems(changes.updated, '~')}
+            {renderSyncPreviewItems(changes.deleted, '−')}
+        </div>
+    );
+
+    const syncPreviewEmpty = syncPreview
+        ? syncPreview.conflictCount === 0
+            && [syncPreview.local, syncPreview.remote].every((changes) =>
+                changes.created.length + changes.updated.length + changes.deleted.length === 0)
+        : false;
+
     const renderSyncToggle = (
         key: keyof NonNullable<AppData['settings']['syncPreferences']>,
         label: string,
@@ -328,6 +651,20 @@ export function SettingsSyncPage({
                 <div className="bg-card border border-border rounded-lg p-6 space-y-4">
                     <p className="text-sm text-muted-foreground">{t.syncDescription}</p>
 
+                    {Object.keys(managedConfigKeys).length > 0 && (
+                        <div className="rounded-md border border-border bg-muted/30 p-3 space-y-1">
+                            <p className="text-sm font-medium">{t.managedSettings}</p>
+                            <p className="text-xs text-muted-foreground">{t.managedSettingsHint}</p>
+                            {Object.entries(managedConfigKeys)
+                                .sort(([a], [b]) => a.localeCompare(b))
+                                .map(([key, source]) => (
+                                    <div key={key} className="text-xs text-muted-foreground">
+                                        <span className="font-mono text-foreground">{key}</span> • {source}
+                                    </div>
+                                ))}
+                        </div>
+                    )}
+
                     <div className="flex items-center justify-between gap-4">
                         <span className="text-sm font-medium">{t.syncBackend}</span>
                         <div className="flex gap-2">
@@ -375,6 +712,45 @@ export function SettingsSyncPage({
                             >
                                 {t.syncBackendCloud}
                             </button>
+                            {isTauri && (
+                                <button
+                                    onClick={() => onSetSyncBackend('s3')}
+                                    className={cn(
+                                        "px-3 py-1.5 rounded-md text-sm font-medium transition-colors border",
+                                        syncBackend === 's3'
+                                            ? "bg-primary/10 text-primary border-primary ring-1 ring-primary"
+                                            : "bg-muted/50 text-muted-foreground border-border hover:bg-muted hover:text-foreground",
+                                    )}
+                                >
+                                    {t.syncBackendS3}
+                                </button>
+                            )}
+                            {isTauri && (
+                                <button
+                                    onClick={() => onSetSyncBackend('git')}
+                                    className={cn(
+                                        "px-3 py-1.5 rounded-md text-sm font-medium transition-colors border",
+                                        syncBackend === 'git'
+                                            ? "bg-primary/10 text-primary border-primary ring-1 ring-primary"
+                                            : "bg-muted/50 text-muted-foreground border-border hover:bg-muted hover:text-foreground",
+                                    )}
+                                >
+                                    {t.syncBackendGit}
+                                </button>
+                            )}
+                            {isTauri && (
+                                <button
+                                    onClick={() => onSetSyncBackend('sftp')}
+                                    className={cn(
+                                        "px-3 py-1.5 rounded-md text-sm font-medium transition-colors border",
+                                        syncBackend === 'sftp'
+                                            ? "bg-primary/10 text-primary border-primary ring-1 ring-primary"
+                                            : "bg-muted/50 text-muted-foreground border-border hover:bg-muted hover:text-foreground",
+                                    )}
+                                >
+                                    {t.syncBackendSftp}
+                                </button>
+                            )}
                         </div>
                     </div>
 
@@ -405,6 +781,27 @@ export function SettingsSyncPage({
                                 </button>
                             </div>
                             <p className="text-xs text-muted-foreground">{t.pathHint}</p>
+                            <div className="flex items-center justify-between gap-4 pt-2">
+                                <span className="text-sm font-medium">{t.syncFileLayout}</span>
+                                <div className="flex gap-2">
+                                    {(['single', 'entities'] as const).map((layout) => (
+                                        <button
+                                            key={layout}
+                                            onClick={() => onSetSyncFileLayout(layout)}
+                                            disabled={!isTauri || !syncPath.trim() || syncFileLayoutBusy}
+                                            className={cn(
+                                                "px-3 py-1.5 rounded-md text-sm font-medium transition-colors border disabled:opacity-50 disabled:cursor-not-allowed",
+                                                syncFileLayout === layout
+                                                    ? "bg-primary/10 text-primary border-primary ring-1 ring-primary"
+                                                    : "bg-muted/50 text-muted-foreground border-border hover:bg-muted hover:text-foreground",
+                                            )}
+                                        >
+                                            {layout === 'single' ? t.syncFileLayoutSingle : t.syncFileLayoutEntities}
+                                        </button>
+                                    ))}
+                                </div>
+                            </div>
+                            <p className="text-xs text-muted-foreground">{t.syncFileLayoutHint}</p>
                         </div>
                     )}
 
@@ -524,6 +921,7 @@ export function SettingsSyncPage({
                                             type="password"
                                             value={cloudToken}
                                             onChange={(e) => onCloudTokenChange(e.target.value)}
+                                            placeholder={cloudHasToken && !cloudToken ? '••••••••' : ''}
                                             className="bg-muted p-2 rounded text-sm border border-border focus:outline-none focus:ring-2 focus:ring-primary"
                                         />
                                     </div>
@@ -591,6 +989,394 @@ export function SettingsSyncPage({
                         </div>
                     )}
 
+                    {syncBackend === 's3' && (
+                        <div className="space-y-3">
+                            <div className="flex flex-col gap-2">
+                                <label className="text-sm font-medium">{t.s3Endpoint}</label>
+                                <input
+                                    type="text"
+                                    value={s3Endpoint}
+                                    onChange={(e) => onS3EndpointChange(e.target.value)}
+                                    placeholder="https://minio.example.com"
+                                    className={cn(
+                                        "bg-muted p-2 rounded text-sm font-mono border focus:outline-none focus:ring-2 focus:ring-primary",
+                                        s3EndpointError ? "border-destructive" : "border-border",
+                                    )}
+                                />
+                                <p className="text-xs text-muted-foreground">{t.s3EndpointHint}</p>
+                                {s3EndpointError && (
+                                    <p className="text-xs text-destructive">Enter a valid http(s) URL.</p>
+                                )}
+                            </div>
+
+                            <div className="grid sm:grid-cols-3 gap-2">
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.s3Bucket}</label>
+                                    <input
+                                        type="text"
+                                        value={s3Bucket}
+                                        onChange={(e) => onS3BucketChange(e.target.value)}
+                                        className="bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.s3Prefix}</label>
+                                    <input
+                                        type="text"
+                                        value={s3Prefix}
+                                        onChange={(e) => onS3PrefixChange(e.target.value)}
+                                        placeholder="mindwtr"
+                                        className="bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.s3Region}</label>
+                                    <input
+                                        type="text"
+                                        value={s3Region}
+                                        onChange={(e) => onS3RegionChange(e.target.value)}
+                                        placeholder="us-east-1"
+                                        className="bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                            </div>
+
+                            <div className="grid sm:grid-cols-2 gap-2">
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.s3AccessKeyId}</label>
+                                    <input
+                                        type="text"
+                                        value={s3AccessKeyId}
+                                        onChange={(e) => onS3AccessKeyIdChange(e.target.value)}
+                                        className="bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.s3SecretKey}</label>
+                                    <input
+                                        type="password"
+                                        value={s3SecretKey}
+                                        onChange={(e) => onS3SecretKeyChange(e.target.value)}
+                                        placeholder={s3HasSecretKey && !s3SecretKey ? '••••••••' : ''}
+                                        className="bg-muted p-2 rounded text-sm border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                            </div>
+
+                            <div className="flex flex-wrap justify-end gap-2">
+                                <button
+                                    onClick={onSaveS3}
+                                    disabled={s3EndpointError || s3Busy}
+                                    className="px-4 py-2 bg-primary text-primary-foreground rounded-md text-sm font-medium hover:bg-primary/90 whitespace-nowrap disabled:bg-muted disabled:text-muted-foreground disabled:cursor-not-allowed"
+                                >
+                                    {t.s3Save}
+                                </button>
+                                <button
+                                    onClick={onTestS3Connection}
+                                    disabled={s3EndpointError || s3Busy || !s3Bucket.trim()}
+                                    className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                >
+                                    {s3Busy ? t.syncing : t.s3Test}
+                                </button>
+                                {s3TestState !== 'idle' && (
+                                    <span
+                                        className={cn(
+                                            "inline-flex items-center rounded-md border px-2 py-1 text-xs",
+                                            s3TestState === 'success'
+                                                ? "border-emerald-600/40 text-emerald-500"
+                                                : "border-destructive/40 text-destructive"
+                                        )}
+                                    >
+                                        {s3TestState === 'success' ? `✓ ${t.s3TestReachable}` : `! ${t.s3TestFailed}`}
+                                    </span>
+                                )}
+                            </div>
+                        </div>
+                    )}
+
+                    {syncBackend === 'sftp' && (
+                        <div className="space-y-3">
+                            <div className="grid sm:grid-cols-[1fr_8rem] gap-2">
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.sftpHost}</label>
+                                    <input
+                                        type="text"
+                                        value={sftpHost}
+                                        onChange={(e) => onSftpHostChange(e.target.value)}
+                                        placeholder="home.example.com"
+                                        className="bg-muted p-2 rounded text-sm border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.sftpPort}</label>
+                                    <input
+                                        type="text"
+                                        inputMode="numeric"
+                                        value={sftpPort}
+                                        onChange={(e) => onSftpPortChange(e.target.value)}
+                                        placeholder="22"
+                                        className={cn(
+                                            "bg-muted p-2 rounded text-sm border focus:outline-none focus:ring-2 focus:ring-primary",
+                                            sftpPortError ? "border-destructive" : "border-border",
+                                        )}
+                                    />
+                                </div>
+                            </div>
+
+                            <div className="grid sm:grid-cols-2 gap-2">
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.sftpUsername}</label>
+                                    <input
+                                        type="text"
+                                        value={sftpUsername}
+                                        onChange={(e) => onSftpUsernameChange(e.target.value)}
+                                        className="bg-muted p-2 rounded text-sm border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.sftpRemotePath}</label>
+                                    <input
+                                        type="text"
+                                        value={sftpRemotePath}
+                                        onChange={(e) => onSftpRemotePathChange(e.target.value)}
+                                        placeholder="mindwtr"
+                                        className="bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                            </div>
+                            <p className="text-xs text-muted-foreground">{t.sftpRemotePathHint}</p>
+
+                            <div className="flex flex-col gap-2">
+                                <label className="text-sm font-medium">{t.sftpKeyPath}</label>
+                                <div className="flex gap-2">
+                                    <input
+                                        type="text"
+                                        value={sftpKeyPath}
+                                        onChange={(e) => onSftpKeyPathChange(e.target.value)}
+                                        placeholder="~/.ssh/id_ed25519"
+                                        className="flex-1 bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                    <button
+                                        onClick={onBrowseSftpKey}
+                                        disabled={sftpBusy}
+                                        className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                    >
+                                        {t.browse}
+                                    </button>
+                                </div>
+                                <p className="text-xs text-muted-foreground">{t.sftpKeyPathHint}</p>
+                            </div>
+                            {sftpKeyPath.trim() && (
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.sftpKeyPassphrase}</label>
+                                    <input
+                                        type="password"
+                                        value={sftpKeyPassphrase}
+                                        onChange={(e) => onSftpKeyPassphraseChange(e.target.value)}
+                                        placeholder={sftpHasKeyPassphrase && !sftpKeyPassphrase ? '••••••••' : ''}
+                                        className="bg-muted p-2 rounded text-sm border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                            )}
+
+                            <div className="flex flex-wrap justify-end gap-2">
+                                <button
+                                    onClick={onSaveSftp}
+                                    disabled={sftpPortError || sftpBusy}
+                                    className="px-4 py-2 bg-primary text-primary-foreground rounded-md text-sm font-medium hover:bg-primary/90 whitespace-nowrap disabled:bg-muted disabled:text-muted-foreground disabled:cursor-not-allowed"
+                                >
+                                    {t.sftpSave}
+                                </button>
+                                <button
+                                    onClick={onTestSftpConnection}
+                                    disabled={sftpPortError || sftpBusy || !sftpHost.trim() || !sftpUsername.trim()}
+                                    className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                >
+                                    {sftpBusy ? t.syncing : t.s3Test}
+                                </button>
+                                {sftpTestState !== 'idle' && (
+                                    <span
+                                        className={cn(
+                                            "inline-flex items-center rounded-md border px-2 py-1 text-xs",
+                                            sftpTestState === 'success'
+                                                ? "border-emerald-600/40 text-emerald-500"
+                                                : "border-destructive/40 text-destructive"
+                                        )}
+                                    >
+                                        {sftpTestState === 'success' ? `✓ ${t.s3TestReachable}` : `! ${t.s3TestFailed}`}
+                                    </span>
+                                )}
+                            </div>
+                        </div>
+                    )}
+
+                    {syncBackend === 'git' && (
+                        <div className="space-y-3">
+                            <div className="flex flex-col gap-2">
+                                <label className="text-sm font-medium">{t.gitRepoPath}</label>
+                                <div className="flex gap-2">
+                                    <input
+                                        type="text"
+                                        value={gitRepoPath}
+                                        onChange={(e) => onGitRepoPathChange(e.target.value)}
+                                        placeholder="/path/to/your/repository"
+                                        className="flex-1 bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                    <button
+                                        onClick={onBrowseGitRepo}
+                                        disabled={gitBusy}
+                                        className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                    >
+                                        {t.browse}
+                                    </button>
+                                </div>
+                                <p className="text-xs text-muted-foreground">{t.gitRepoHint}</p>
+                            </div>
+
+                            <div className="grid sm:grid-cols-2 gap-2">
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.gitRemote}</label>
+                                    <input
+                                        type="text"
+                                        value={gitRemote}
+                                        onChange={(e) => onGitRemoteChange(e.target.value)}
+                                        placeholder="origin"
+                                        className="bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                                <div className="flex flex-col gap-2">
+                                    <label className="text-sm font-medium">{t.gitBranch}</label>
+                                    <input
+                                        type="text"
+                                        value={gitBranch}
+                                        onChange={(e) => onGitBranchChange(e.target.value)}
+                                        className="bg-muted p-2 rounded text-sm font-mono border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                    />
+                                </div>
+                            </div>
+                            <p className="text-xs text-muted-foreground">{t.gitBranchHint}</p>
+
+                            <div className="flex flex-wrap justify-end gap-2">
+                                <button
+                                    onClick={onSaveGit}
+                                    disabled={gitBusy}
+                                    className="px-4 py-2 bg-primary text-primary-foreground rounded-md text-sm font-medium hover:bg-primary/90 whitespace-nowrap disabled:bg-muted disabled:text-muted-foreground disabled:cursor-not-allowed"
+                                >
+                                    {t.gitSave}
+                                </button>
+                                <button
+                                    onClick={onTestGitConnection}
+                                    disabled={gitBusy || !gitRepoPath.trim()}
+                                    className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                >
+                                    {gitBusy ? t.syncing : t.gitTest}
+                                </button>
+                                {gitTestState !== 'idle' && (
+                                    <span
+                                        className={cn(
+                                            "inline-flex items-center rounded-md border px-2 py-1 text-xs",
+                                            gitTestState === 'success'
+                                                ? "border-emerald-600/40 text-emerald-500"
+                                                : "border-destructive/40 text-destructive"
+                                        )}
+                                    >
+                                        {gitTestState === 'success' ? `✓ ${t.s3TestReachable}` : `! ${t.s3TestFailed}`}
+                                    </span>
+                                )}
+                            </div>
+                        </div>
+                    )}
+
+                    {showSecretVault && secretVault && (
+                        <div className="space-y-2">
+                            <div className="flex items-center justify-between gap-4">
+                                <span className="text-sm font-medium">{t.secretVault}</span>
+                                <span className={cn("text-xs", secretVault.unlocked ? "text-emerald-500" : "text-muted-foreground")}>
+                                    {!secretVault.exists
+                                        ? (secretVault.plaintextAllowed ? t.secretVaultPlaintextActive : t.secretVaultMissing)
+                                        : secretVault.unlocked
+                                            ? t.secretVaultUnlocked.replace('{count}', String(secretVault.storedSecrets))
+                                            : t.secretVaultLocked}
+                                </span>
+                            </div>
+                            <p className="text-xs text-muted-foreground">{t.secretVaultHint}</p>
+                            {secretVault.unlocked ? (
+                                <div className="flex justify-end">
+                                    <button
+                                        type="button"
+                                        onClick={() => void onLockSecretVault()}
+                                        disabled={secretVaultBusy}
+                                        className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                    >
+                                        {t.secretVaultLock}
+                                    </button>
+                                </div>
+                            ) : (
+                                <>
+                                    <div className={cn("grid gap-2", !secretVault.exists && "sm:grid-cols-2")}>
+                                        <input
+                                            type="password"
+                                            value={secretVaultPassphrase}
+                                            onChange={(e) => onSecretVaultPassphraseChange(e.target.value)}
+                                            placeholder={t.syncEncryptionPassphrase}
+                                            aria-label={t.syncEncryptionPassphrase}
+                                            autoComplete={secretVault.exists ? 'current-password' : 'new-password'}
+                                            className="bg-muted p-2 rounded text-sm border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                        />
+                                        {!secretVault.exists && (
+                                            <input
+                                                type="password"
+                                                value={secretVaultPassphraseConfirm}
+                                                onChange={(e) => onSecretVaultPassphraseConfirmChange(e.target.value)}
+                                                placeholder={t.syncEncryptionPassphraseConfirm}
+                                                aria-label={t.syncEncryptionPassphraseConfirm}
+                                                autoComplete="new-password"
+                                                className={cn(
+                                                    "bg-muted p-2 rounded text-sm border focus:outline-none focus:ring-2 focus:ring-primary",
+                                                    secretVaultMismatch ? "border-destructive" : "border-border",
+                                                )}
+                                            />
+                                        )}
+                                    </div>
+                                    {secretVaultMismatch && (
+                                        <p className="text-xs text-destructive">{t.syncEncryptionMismatch}</p>
+                                    )}
+                                    <div className="flex justify-end gap-2">
+                                        {!secretVault.exists && !secretVault.plaintextAllowed && (
+                                            <button
+                                                type="button"
+                                                onClick={() => void onAllowPlaintextSecrets()}
+                                                disabled={secretVaultBusy}
+                                                className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                            >
+                                                {t.secretVaultPlaintext}
+                                            </button>
+                                        )}
+                                        {secretVault.exists ? (
+                                            <button
+                                                type="button"
+                                                onClick={() => void onUnlockSecretVault()}
+                                                disabled={!secretVaultPassphrase || secretVaultBusy}
+                                                className="px-4 py-2 bg-primary text-primary-foreground rounded-md text-sm font-medium hover:bg-primary/90 whitespace-nowrap disabled:bg-muted disabled:text-muted-foreground disabled:cursor-not-allowed"
+                                            >
+                                                {t.secretVaultUnlock}
+                                            </button>
+                                        ) : (
+                                            <button
+                                                type="button"
+                                                onClick={() => setSecretVaultCreateOpen(true)}
+                                                disabled={!secretVaultCreateReady || secretVaultBusy}
+                                                className="px-4 py-2 bg-primary text-primary-foreground rounded-md text-sm font-medium hover:bg-primary/90 whitespace-nowrap disabled:bg-muted disabled:text-muted-foreground disabled:cursor-not-allowed"
+                                            >
+                                                {t.secretVaultCreate}
+                                            </button>
+                                        )}
+                                    </div>
+                                </>
+                            )}
+                        </div>
+                    )}
+
                     <div className="border-t border-border/60 pt-4 space-y-3">
                         <button
                             type="button"
@@ -613,6 +1399,106 @@ export function SettingsSyncPage({
                         )}
                     </div>
 
+                    {isSyncTargetValid && isTauri && (
+                        <div className="space-y-2">
+                            <div className="flex items-center justify-between gap-4">
+                                <span className="text-sm font-medium">{t.syncEncryption}</span>
+                                <span className={cn("text-xs", syncEncryption.enabled ? "text-emerald-500" : "text-muted-foreground")}>
+                                    {syncEncryption.enabled
+                                        ? t.syncEncryptionOn.replace('{keyId}', syncEncryption.keyId ?? '')
+                                        : t.syncEncryptionOff}
+                                </span>
+                            </div>
+                            <p className="text-xs text-muted-foreground">{t.syncEncryptionHint}</p>
+                            {syncEncryption.rotationPending && (
+                                <p className="text-xs text-amber-600">{t.syncEncryptionRotationPending}</p>
+                            )}
+                            <div className="grid sm:grid-cols-2 gap-2">
+                                <input
+                                    type="password"
+                                    value={syncEncryptionPassphrase}
+                                    onChange={(e) => onSyncEncryptionPassphraseChange(e.target.value)}
+                                    placeholder={t.syncEncryptionPassphrase}
+                                    aria-label={t.syncEncryptionPassphrase}
+                                    autoComplete="new-password"
+                                    className="bg-muted p-2 rounded text-sm border border-border focus:outline-none focus:ring-2 focus:ring-primary"
+                                />
+                                <input
+                                    type="password"
+                                    value={syncEncryptionPassphraseConfirm}
+                                    onChange={(e) => onSyncEncryptionPassphraseConfirmChange(e.target.value)}
+                                    placeholder={t.syncEncryptionPassphraseConfirm}
+                                    aria-label={t.syncEncryptionPassphraseConfirm}
+                                    autoComplete="new-password"
+                                    className={cn(
+                                        "bg-muted p-2 rounded text-sm border focus:outline-none focus:ring-2 focus:ring-primary",
+                                        encryptionPassphraseMismatch ? "border-destructive" : "border-border",
+                                    )}
+                                />
+                            </div>
+                            {encryptionPassphraseMismatch && (
+                                <p className="text-xs text-destructive">{t.syncEncryptionMismatch}</p>
+                            )}
+                            <div className="flex justify-end gap-2">
+                                {syncEncryption.enabled ? (
+                                    <>
+                                        <button
+                                            type="button"
+                                            onClick={() => setEncryptionAction('disable')}
+                                            disabled={syncEncryptionBusy || isSyncing}
+                                            className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                        >
+                                            {t.syncEncryptionDisable}
+                                        </button>
+                                        <button
+                                            type="button"
+                                            onClick={() => setEncryptionAction('rotate')}
+                                            disabled={!encryptionPassphraseReady || syncEncryptionBusy || isSyncing}
+                                            className="px-4 py-2 bg-primary text-primary-foreground rounded-md text-sm font-medium hover:bg-primary/90 whitespace-nowrap disabled:bg-muted disabled:text-muted-foreground disabled:cursor-not-allowed"
+                                        >
+                                            {t.syncEncryptionRotate}
+                                        </button>
+                                    </>
+                                ) : (
+                                    <button
+                                        type="button"
+                                        onClick={() => setEncryptionAction('enable')}
+                                        disabled={!encryptionPassphraseReady || syncEncryptionBusy || isSyncing}
+                                        className="px-4 py-2 bg-primary text-primary-foreground rounded-md text-sm font-medium hover:bg-primary/90 whitespace-nowrap disabled:bg-muted disabled:text-muted-foreground disabled:cursor-not-allowed"
+                                    >
+                                        {t.syncEncryptionEnable}
+                                    </button>
+                                )}
+                            </div>
+                        </div>
+                    )}
+
+                    {isSyncTargetValid && isTauri && (
+                        <div className="space-y-2">
+                            <span className="text-sm font-medium">{t.syncInterval}</span>
+                            <div className="flex flex-wrap gap-2">
+                                {SYNC_INTERVAL_OPTIONS.map((minutes) => (
+                                    <button
+                                        key={minutes}
+                                        type="button"
+                                        onClick={() => onSetSyncIntervalMinutes(minutes)}
+                                        className={cn(
+                                            "px-3 py-1.5 rounded-md text-sm font-medium transition-colors border",
+                                            syncIntervalMinutes === minutes
+                                                ? "bg-primary/10 text-primary border-primary ring-1 ring-primary"
+                                                : "bg-muted/50 text-muted-foreground border-border hover:bg-muted hover:text-foreground",
+                                        )}
+                                    >
+                                        {minutes === 0
+                                            ? t.syncIntervalOff
+                                            : t.syncIntervalMinutes.replace('{minutes}', String(minutes))}
+                                    </button>
+                                ))}
+                            </div>
+                            <p className="text-xs text-muted-foreground">{t.syncIntervalHint}</p>
+                        </div>
+                    )}
+
                     {isSyncTargetValid && (
                         <div className="pt-2 flex items-center gap-3">
                             <button
@@ -626,6 +1512,16 @@ export function SettingsSyncPage({
                                 <RefreshCw className={cn("w-4 h-4", isSyncing && "animate-spin")} />
                                 {isSyncing ? t.syncing : t.syncNow}
                             </button>
+                            {isTauri && (
+                                <button
+                                    type="button"
+                                    onClick={onPreviewSync}
+                                    disabled={isSyncing || syncPreviewBusy}
+                                    className="px-4 py-2 bg-secondary text-secondary-foreground rounded-md text-sm font-medium hover:bg-secondary/90 whitespace-nowrap disabled:opacity-50 disabled:cursor-not-allowed"
+                                >
+                                    {syncPreviewBusy ? t.syncPreviewing : t.syncPreview}
+                                </button>
+                            )}
                             {syncStatusLabel && (
                                 <span className={cn("text-xs", syncStatusTone)}>
                                     {syncStatusLabel}
@@ -635,6 +1531,28 @@ export function SettingsSyncPage({
                         </div>
                     )}
 
+                    {isSyncTargetValid && syncPreview && (
+                        <div className="rounded-md border border-border p-3 text-xs space-y-2">
+                            <p className="text-muted-foreground">{t.syncPreviewHint}</p>
+                            {syncPreviewEmpty ? (
+                                <div>{t.syncPreviewNoChanges}</div>
+                            ) : (
+                                <>
+                                    {renderSyncPreviewSide(t.syncPreviewLocal, syncPreview.local)}
+                                    {renderSyncPreviewSide(t.syncPreviewRemote, syncPreview.remote)}
+                                    {syncPreview.conflictCount > 0 && (
+                                        <div className="space-y-0.5">
+                                            <div className="font-medium text-amber-600">
+                                                {t.syncPreviewConflicts}: {syncPreview.conflictCount}
+                                            </div>
+                                            {renderSyncPreviewItems(syncPreview.conflicts, '!')}
+                                        </div>
+                                    )}
+                                </>
+                            )}
+                        </div>
+                    )}
+
                     <div className="pt-3 text-xs text-muted-foreground space-y-1">
                         <div>
                             {t.lastSync}: {lastSyncDisplay}
@@ -642,6 +1560,11 @@ export function SettingsSyncPage({
                             {lastSyncStatus === 'conflict' && ` • ${t.lastSyncConflict}`}
                             {lastSyncStatus === 'error' && ` • ${t.lastSyncError}`}
                         </div>
+                        {lastSuccessfulSyncDisplay && (
+                            <div>
+                                {t.lastSuccessfulSync}: {lastSuccessfulSyncDisplay}
+                            </div>
+                        )}
                         {lastSyncStats && (
                             <div>
                                 {t.lastSyncConflicts}: {conflictCount} • Tasks {lastSyncStats.tasks.mergedTotal} /
@@ -704,6 +1627,45 @@ export function SettingsSyncPage({
                                 )}
                             </div>
                         )}
+                        {syncJournalEntries.length > 0 && (
+                            <div className="pt-2 space-y-1">
+                                <button
+                                    type="button"
+                                    onClick={() => setSyncJournalOpen((prev) => !prev)}
+                                    className="w-full flex items-center justify-between text-left"
+                                    aria-expanded={syncJournalOpen}
+                                >
+                                    <span className="text-xs font-medium text-muted-foreground">{t.syncJournal}</span>
+                                    <span className="text-muted-foreground">{syncJournalOpen ? '▾' : '▸'}</span>
+                                </button>
+                                {syncJournalOpen && (
+                                    <div className="space-y-1">
+                                        {syncJournalEntries.slice(0, 10).map((entry) => {
+                                            const timestamp = safeFormatDate(entry.startedAt, 'PPpp', entry.startedAt);
+                                            const durationMs = entry.finishedAt ? Date.parse(entry.finishedAt) - Date.parse(entry.startedAt) : NaN;
+                                            const changes = t.syncJournalChanges
+                                                .replace('{added}', String(entry.added))
+                                                .replace('{updated}', String(entry.updated))
+                                                .replace('{deleted}', String(entry.deleted));
+                                            const parts = [
+                                                `Backend: ${entry.backend}`,
+                                                changes,
+                                                entry.conflicts ? `${t.lastSyncConflicts}: ${entry.conflicts}` : null,
+                                                formatByteCount(entry.bytes),
+                                                Number.isFinite(durationMs) ? formatClockSkew(durationMs) : null,
+                                            ].filter(Boolean);
+                                            return (
+                                                <div key={entry.id ?? entry.startedAt} className="text-xs text-muted-foreground">
+                                                    <span className="text-foreground">{timestamp}</span> • {entry.error ? t.lastSyncError : t.lastSyncSuccess}
+                                                    {` • ${parts.join(' • ')}`}
+                                                    {entry.error && <span className="text-destructive break-all"> • {entry.error}</span>}
+                                                </div>
+                                            );
+                                        })}
+                                    </div>
+                                )}
+                            </div>
+                        )}
                         <div className="pt-3 space-y-1">
                             <button
                                 type="button"
@@ -760,6 +1722,35 @@ export function SettingsSyncPage({
                 }}
             />
 
+            <ConfirmModal
+                isOpen={secretVaultCreateOpen}
+                title={t.secretVaultCreate}
+                description={t.secretVaultCreateConfirm}
+                confirmLabel={t.secretVaultCreate}
+                cancelLabel={t.recoverySnapshotsConfirmCancel}
+                onCancel={() => setSecretVaultCreateOpen(false)}
+                onConfirm={() => {
+                    setSecretVaultCreateOpen(false);
+                    void onCreateSecretVault();
+                }}
+            />
+
+            <ConfirmModal
+                isOpen={encryptionAction !== null}
+                title={encryptionAction ? encryptionConfirmCopy[encryptionAction].title : ''}
+                description={encryptionAction ? encryptionConfirmCopy[encryptionAction].description : undefined}
+                confirmLabel={encryptionAction ? encryptionConfirmCopy[encryptionAction].title : ''}
+                cancelLabel={t.recoverySnapshotsConfirmCancel}
+                onCancel={() => setEncryptionAction(null)}
+                onConfirm={() => {
+                    const action = encryptionAction;
+                    setEncryptionAction(null);
+                    if (action === 'enable') void onEnableSyncEncryption();
+                    if (action === 'rotate') void onRotateSyncEncryptionKey();
+                    if (action === 'disable') void onDisableSyncEncryption();
+                }}
+            />
+
             <section className="space-y-3">
                 <h2 className="text-lg font-semibold flex items-center gap-2">
                     <Trash2 className="w-5 h-5" />
@@ -101,6 +101,22 @@ export const labelFallback = {
         aiBaseUrlHint: 'Optional. For local OpenAI-compatible servers (e.g., http://localhost:11434/v1).',
         aiApiKey: 'API key',
         aiApiKeyHint: 'Stored locally on this device. Never synced. For local endpoints, leave blank if auth is not required.',
+        aiCustomProviders: 'Custom providers',
+        aiCustomProvidersHint: 'Add self-hosted or proxy endpoints. Each provider keeps its own API key, stored on this device only.',
+        aiProviderId: 'ID (e.g. ollama)',
+        aiProviderName: 'Display name',
+        aiProviderKind: 'API type',
+        aiProviderKindOpenAI: 'OpenAI-compatible',
+        aiProviderBaseUrl: 'Base URL',
+        aiProviderDefaultModel: 'Default model',
+        aiProviderAdd: 'Add provider',
+        aiProviderRemove: 'Remove',
+        aiProviderKindLocal: 'On-device model (GGUF)',
+        aiProviderModelFile: 'Model file',
+        aiProviderChooseModel: 'Choose…',
+        aiLocalNoKey: 'This provider runs a model on this device and needs no API key. Requests never leave the computer.',
+        aiBaseUrlMoved: 'The custom base URL ({url}) is no longer used by the desktop app. Add it as a custom provider, then enter its API key.',
+        aiBaseUrlMove: 'Add as provider',
         aiReasoning: 'Reasoning effort',
         aiReasoningHint: 'Used by GPT-5 models.',
         aiEffortLow: 'Low',
@@ -177,6 +193,9 @@ export const labelFallback = {
         syncBackendFile: 'File',
         syncBackendWebdav: 'WebDAV',
         syncBackendCloud: 'Self-Hosted',
+        syncBackendS3: 'S3',
+        syncBackendGit: 'Git',
+        syncBackendSftp: 'SFTP',
         syncPreferences: 'Settings sync options',
         syncPreferencesDesc: 'Choose which preferences stay in sync across devices.',
         syncPreferenceAppearance: 'Appearance (theme, density)',
@@ -236,6 +255,63 @@ export const labelFallback = {
         dropboxTest: 'Test connection',
         dropboxTestReachable: 'Reachable',
         dropboxTestFailed: 'Failed',
+        s3Endpoint: 'Endpoint',
+        s3EndpointHint: 'Leave empty for Amazon S3. For MinIO, R2 or other S3-compatible storage, enter the server URL (e.g., https://minio.example.com).',
+        s3Bucket: 'Bucket',
+        s3Prefix: 'Folder prefix',
+        s3Region: 'Region',
+        s3AccessKeyId: 'Access key ID',
+        s3SecretKey: 'Secret access key',
+        s3Save: 'Save S3',
+        s3Test: 'Test connection',
+        s3TestReachable: 'Reachable',
+        s3TestFailed: 'Failed',
+        sftpHost: 'Host',
+        sftpPort: 'Port',
+        sftpUsername: 'Username',
+        sftpRemotePath: 'Remote folder',
+        sftpRemotePathHint: 'Relative to your login directory unless it starts with /. The server must already be in ~/.ssh/known_hosts: connect once with ssh to trust it.',
+        sftpKeyPath: 'Private key',
+        sftpKeyPathHint: 'Leave empty to use the keys loaded in ssh-agent.',
+        sftpKeyPassphrase: 'Key passphrase',
+        sftpSave: 'Save SFTP',
+        gitRepoPath: 'Repository folder',
+        gitRepoHint: 'A local clone whose remote you can push to. Each sync commits data.json and pushes it; attachments are not synced.',
+        gitRemote: 'Remote',
+        gitBranch: 'Branch',
+        gitBranchHint: 'Leave empty to use origin and the branch that is checked out.',
+        gitSave: 'Save Git',
+        gitTest: 'Test remote',
+        syncFileLayout: 'Folder layout',
+        syncFileLayoutSingle: 'Single file',
+        syncFileLayoutEntities: 'One file per item',
+        syncFileLayoutHint: 'One file per item keeps sync-tool conflicts to the items that changed. Switching converts the folder in place; every desktop device must run this version, and mobile file sync only reads the single file.',
+        syncInterval: 'Background sync',
+        syncIntervalOff: 'On changes only',
+        syncIntervalMinutes: 'Every {minutes} min',
+        syncIntervalHint: 'Edits and files changed in the sync folder by other devices always trigger a sync. Failed syncs are retried with increasing delays.',
+        syncPreview: 'Preview',
+        syncPreviewing: 'Checking…',
+        syncPreviewHint: 'Dry run: this is what Sync now would do. Nothing has been written on either side.',
+        syncPreviewLocal: 'This device',
+        syncPreviewRemote: 'Remote',
+        syncPreviewCounts: '{created} new, {updated} updated, {deleted} deleted',
+        syncPreviewConflicts: 'Conflicts',
+        syncPreviewNoChanges: 'Already in sync; nothing would change.',
+        syncEncryption: 'End-to-end encryption',
+        syncEncryptionHint: 'Data and attachments are encrypted on this device before they are uploaded. Every desktop device needs the same passphrase, and the passphrase cannot be recovered. Mobile apps cannot sync encrypted data.',
+        syncEncryptionOn: 'On (key {keyId})',
+        syncEncryptionOff: 'Off',
+        syncEncryptionRotationPending: 'A passphrase change did not finish. Enter the new passphrase and change it again to complete it.',
+        syncEncryptionPassphrase: 'Passphrase',
+        syncEncryptionPassphraseConfirm: 'Repeat passphrase',
+        syncEncryptionMismatch: 'The passphrases do not match.',
+        syncEncryptionEnable: 'Enable encryption',
+        syncEncryptionEnableConfirm: 'The sync data and attachments will be encrypted with this passphrase. Other desktop devices must enter the same passphrase, and mobile apps will stop syncing until encryption is turned off. If the data is already encrypted, this device joins it instead.',
+        syncEncryptionRotate: 'Change passphrase',
+        syncEncryptionRotateConfirm: 'All sync data and attachments will be re-encrypted with the new passphrase. Other desktop devices must enter the new passphrase before they can sync again.',
+        syncEncryptionDisable: 'Disable encryption',
+        syncEncryptionDisableConfirm: 'The sync data and attachments will be decrypted and stored as plain files again. Other desktop devices keep working; mobile apps can sync again.',
         lastSync: 'Last sync',
         lastSyncNever: 'Never',
         lastSyncSuccess: 'Sync completed',
@@ -246,6 +322,22 @@ export const labelFallback = {
         lastSyncAdjusted: 'Timestamp fixes',
         lastSyncConflictIds: 'Conflict IDs',
         syncHistory: 'Sync history',
+        lastSuccessfulSync: 'Last successful sync',
+        syncJournal: 'Sync journal',
+        syncJournalChanges: 'added {added}, updated {updated}, deleted {deleted}',
+        secretVault: 'Credential vault',
+        secretVaultHint: 'No system keyring is available, so passwords and keys are kept in a vault encrypted with a passphrase you choose. Create one before saving credentials, or choose to store them unencrypted in secrets.toml.',
+        secretVaultLocked: 'Locked',
+        secretVaultUnlocked: 'Unlocked ({count} stored)',
+        secretVaultMissing: 'Not set up',
+        secretVaultUnlock: 'Unlock',
+        secretVaultLock: 'Lock',
+        secretVaultCreate: 'Create vault',
+        secretVaultCreateConfirm: 'The vault will be encrypted with this passphrase. It cannot be recovered; if you forget it, you will need to enter your credentials again.',
+        secretVaultPlaintext: 'Store unencrypted instead',
+        secretVaultPlaintextActive: 'Not set up, secrets are stored unencrypted',
+        managedSettings: 'Managed settings',
+        managedSettingsHint: 'These settings are set by your administrator or the environment and cannot be changed here.',
         recoverySnapshots: 'Recovery snapshots',
         recoverySnapshotsDesc: 'Created before sync. Kept for up to 7 days (max 5 files).',
         recoverySnapshotsLoading: 'Loading snapshots…',
@@ -391,6 +483,22 @@ export const labelFallback = {
         aiBaseUrlHint: '可选。用于本地 OpenAI 兼容服务（例如 http://localhost:11434/v1）。',
         aiApiKey: 'API 密钥',
         aiApiKeyHint: '仅保存在本机，不会同步。本地端点若无需鉴权可留空。',
+        aiCustomProviders: '自定义服务商',
+        aiCustomProvidersHint: '添加自托管或代理端点。每个服务商使用各自的 API 密钥，仅保存在本机。',
+        aiProviderId: 'ID（例如 ollama）',
+        aiProviderName: '显示名称',
+        aiProviderKind: 'API 类型',
+        aiProviderKindOpenAI: 'OpenAI 兼容',
+        aiProviderBaseUrl: '基础 URL',
+        aiProviderDefaultModel: '默认模型',
+        aiProviderAdd: '添加服务商',
+        aiProviderRemove: '移除',
+        aiProviderKindLocal: '本地模型（GGUF）',
+        aiProviderModelFile: '模型文件',
+        aiProviderChooseModel: '选择…',
+        aiLocalNoKey: '此服务在本机运行模型，无需 API 密钥，请求不会离开这台电脑。',
+        aiBaseUrlMoved: '桌面端不再使用自定义基础 URL（{url}）。请将其添加为自定义服务，然后输入它的 API 密钥。',
+        aiBaseUrlMove: '添加为服务',
         aiReasoning: '推理强度',
         aiReasoningHint: '仅用于 GPT-5 模型。',
         aiEffortLow: '低',
@@ -467,6 +575,9 @@ export const labelFallback = {
         syncBackendFile: '文件',
         syncBackendWebdav: 'WebDAV',
         syncBackendCloud: '自托管',
+        syncBackendS3: 'S3',
+        syncBackendGit: 'Git',
+        syncBackendSftp: 'SFTP',
         syncPreferences: '设置同步选项',
         syncPreferencesDesc: '选择要在设备间同步的偏好设置。',
         syncPreferenceAppearance: '外观（主题、密度）',
@@ -526,6 +637,63 @@ export const labelFallback = {
         dropboxTest: '测试连接',
         dropboxTestReachable: '可访问',
         dropboxTestFailed: '失败',
+        s3Endpoint: '服务地址',
+        s3EndpointHint: '使用 Amazon S3 时留空；使用 MinIO、R2 等 S3 兼容存储时填写服务器地址（例如 https://minio.example.com）。',
+        s3Bucket: '存储桶',
+        s3Prefix: '目录前缀',
+        s3Region: '区域',
+        s3AccessKeyId: 'Access Key ID',
+        s3SecretKey: 'Secret Access Key',
+        s3Save: '保存 S3',
+        s3Test: '测试连接',
+        s3TestReachable: '可访问',
+        s3TestFailed: '失败',
+        sftpHost: '主机',
+        sftpPort: '端口',
+        sftpUsername: '用户名',
+        sftpRemotePath: '远程目录',
+        sftpRemotePathHint: '除非以 / 开头，否则相对于登录目录。服务器必须已在 ~/.ssh/known_hosts 中：先用 ssh 连接一次以信任它。',
+        sftpKeyPath: '私钥',
+        sftpKeyPathHint: '留空则使用 ssh-agent 中已加载的密钥。',
+        sftpKeyPassphrase: '私钥密码',
+        sftpSave: '保存 SFTP',
+        gitRepoPath: '仓库目录',
+        gitRepoHint: '一个可以推送到远程的本地克隆。每次同步都会提交 data.json 并推送；附件不会同步。',
+        gitRemote: '远程',
+        gitBranch: '分支',
+        gitBranchHint: '留空则使用 origin 和当前检出的分支。',
+        gitSave: '保存 Git',
+        gitTest: '测试远程',
+        syncFileLayout: '文件夹结构',
+        syncFileLayoutSingle: '单个文件',
+        syncFileLayoutEntities: '每项一个文件',
+        syncFileLayoutHint: '每项一个文件可让同步工具的冲突只局限于被修改的条目。切换会直接转换该文件夹；所有桌面设备都需运行此版本，移动端文件同步只读取单个文件。',
+        syncInterval: '后台同步',
+        syncIntervalOff: '仅在更改时',
+        syncIntervalMinutes: '每 {minutes} 分钟',
+        syncIntervalHint: '本地编辑以及其他设备在同步文件夹中修改的文件始终会触发同步。同步失败后会以逐渐增加的间隔重试。',
+        syncPreview: '预览',
+        syncPreviewing: '正在检查…',
+        syncPreviewHint: '试运行：这是“立即同步”将执行的操作。两端都没有写入任何内容。',
+        syncPreviewLocal: '本设备',
+        syncPreviewRemote: '远程',
+        syncPreviewCounts: '新增 {created}，更新 {updated}，删除 {deleted}',
+        syncPreviewConflicts: '冲突',
+        syncPreviewNoChanges: '已同步，不会有任何更改。',
+        syncEncryption: '端到端加密',
+        syncEncryptionHint: '数据和附件在上传前会先在本设备上加密。所有桌面设备都需要使用相同的口令，且口令无法找回。移动端应用无法同步加密数据。',
+        syncEncryptionOn: '已开启（密钥 {keyId}）',
+        syncEncryptionOff: '未开启',
+        syncEncryptionRotationPending: '上次更改口令未完成。请输入新口令并再次更改以完成操作。',
+        syncEncryptionPassphrase: '口令',
+        syncEncryptionPassphraseConfirm: '再次输入口令',
+        syncEncryptionMismatch: '两次输入的口令不一致。',
+        syncEncryptionEnable: '开启加密',
+        syncEncryptionEnableConfirm: '同步数据和附件将使用此口令加密。其他桌面设备必须输入相同的口令，移动端应用将停止同步，直到关闭加密。如果数据已经加密，本设备将改为加入现有加密。',
+        syncEncryptionRotate: '更改口令',
+        syncEncryptionRotateConfirm: '所有同步数据和附件将使用新口令重新加密。其他桌面设备必须输入新口令后才能继续同步。',
+        syncEncryptionDisable: '关闭加密',
+        syncEncryptionDisableConfirm: '同步数据和附件将被解密并重新以普通文件存储。其他桌面设备可继续使用；移动端应用可以恢复同步。',
         lastSync: '上次同步',
         lastSyncNever: '从未同步',
         lastSyncSuccess: '同步完成',
@@ -536,6 +704,22 @@ export const labelFallback = {
         lastSyncAdjusted: '时间修正',
         lastSyncConflictIds: '冲突 ID',
         syncHistory: '同步历史',
+        lastSuccessfulSync: '上次成功同步',
+        syncJournal: '同步日志',
+        syncJournalChanges: '新增 {added}，更新 {updated}，删除 {deleted}',
+        secretVault: '凭据保险库',
+        secretVaultHint: '系统密钥环不可用，因此密码和密钥保存在由你设定的口令加密的保险库中。请在保存凭据前创建保险库，或选择将其以未加密形式保存在 secrets.toml 中。',
+        secretVaultLocked: '已锁定',
+        secretVaultUnlocked: '已解锁（已保存 {count} 项）',
+        secretVaultMissing: '未设置',
+        secretVaultUnlock: '解锁',
+        secretVaultLock: '锁定',
+        secretVaultCreate: '创建保险库',
+        secretVaultCreateConfirm: '保险库将使用此口令加密。口令无法找回；如果忘记，需要重新输入所有凭据。',
+        secretVaultPlaintext: '改为不加密保存',
+        secretVaultPlaintextActive: '未设置，凭据以未加密形式保存',
+        managedSettings: '受管设置',
+        managedSettingsHint: '这些设置由管理员或环境变量指定，无法在此更改。',
         recoverySnapshots: '恢复快照',
         recoverySnapshotsDesc: '同步前创建。最多保留 7 天（最多 5 个文件）。',
         recoverySnapshotsLoading: '正在加载快照…',
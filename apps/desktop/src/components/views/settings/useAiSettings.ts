@@ -1,5 +1,13 @@
 import { useCallback, useEffect, useState } from 'react';
-import type { AIProviderId, AIReasoningEffort, AppData, AudioCaptureMode, AudioFieldStrategy } from '@mindwtr/core';
+import type {
+    AIProviderDefinition,
+    AIProviderId,
+    AIProviderInfo,
+    AIReasoningEffort,
+    AppData,
+    AudioCaptureMode,
+    AudioFieldStrategy,
+} from '@mindwtr/core';
 import {
     DEFAULT_ANTHROPIC_THINKING_BUDGET,
     DEFAULT_GEMINI_THINKING_BUDGET,
@@ -8,10 +16,11 @@ import {
     getDefaultCopilotModel,
     getCopilotModelOptions,
     getModelOptions,
+    isBuiltInAIProvider,
 } from '@mindwtr/core';
 import { BaseDirectory, exists, mkdir, remove, size, writeFile } from '@tauri-apps/plugin-fs';
 import { dataDir, join } from '@tauri-apps/api/path';
-import { loadAIKey, saveAIKey } from '../../../lib/ai-config';
+import { getAIProviders, hasAIKey, loadAIKey, saveAIKey, saveAIProviders } from '../../../lib/ai-config';
 import { reportError } from '../../../lib/report-error';
 import { logWarn } from '../../../lib/app-log';
 import {
@@ -35,23 +44,39 @@ type SpeechSettingsUpdate = Partial<NonNullable<NonNullable<AppData['settings']>
 
 export function useAiSettings({ isTauri, settings, updateSettings, showSaved, enabled = true }: UseAiSettingsOptions) {
     const [aiApiKey, setAiApiKey] = useState('');
+    const [aiHasKey, setAiHasKey] = useState(false);
     const [speechApiKey, setSpeechApiKey] = useState('');
+    const [speechHasKey, setSpeechHasKey] = useState(false);
     const [speechDownloadState, setSpeechDownloadState] = useState<'idle' | 'downloading' | 'success' | 'error'>('idle');
     const [speechDownloadError, setSpeechDownloadError] = useState<string | null>(null);
     const [speechOfflinePath, setSpeechOfflinePath] = useState<string | null>(null);
     const [speechOfflineSize, setSpeechOfflineSize] = useState<number | null>(null);
+    const [aiProviders, setAiProviders] = useState<AIProviderInfo[]>([]);
+    const [aiProvidersError, setAiProvidersError] = useState<string | null>(null);
 
     const aiProvider = (settings?.ai?.provider ?? 'openai') as AIProviderId;
     const aiEnabled = settings?.ai?.enabled === true;
     const aiDefaults = getDefaultAIConfig(aiProvider);
-    const aiModel = settings?.ai?.model ?? aiDefaults.model;
+    // Providers from the desktop registry bring their own default model and no model catalog;
+    // an on-device provider without one is named after its model file, as in the backend.
+    const aiRegistryProvider = isBuiltInAIProvider(aiProvider)
+        ? null
+        : aiProviders.find((provider) => provider.id === aiProvider) ?? null;
+    const aiRegistryModel = aiRegistryProvider?.defaultModel
+        || (aiRegistryProvider?.modelPath ?? '').split(/[\\/]/).pop()?.replace(/\.gguf$/i, '')
+        || '';
+    const aiKeyless = aiRegistryProvider?.kind === 'local';
+    const aiModel = settings?.ai?.model ?? (isBuiltInAIProvider(aiProvider) ? aiDefaults.model : aiRegistryModel);
     const aiBaseUrl = settings?.ai?.baseUrl ?? '';
     const aiReasoningEffort = (settings?.ai?.reasoningEffort ?? DEFAULT_REASONING_EFFORT) as AIReasoningEffort;
     const aiThinkingBudget = settings?.ai?.thinkingBudget ?? aiDefaults.thinkingBudget ?? DEFAULT_GEMINI_THINKING_BUDGET;
     const anthropicThinkingEnabled = aiProvider === 'anthropic' && aiThinkingBudget > 0;
-    const aiModelOptions = getModelOptions(aiProvider);
-    const aiCopilotModel = settings?.ai?.copilotModel ?? getDefaultCopilotModel(aiProvider);
-    const aiCopilotOptions = getCopilotModelOptions(aiProvider);
+    const aiModelOptions = isBuiltInAIProvider(aiProvider)
+        ? getModelOptions(aiProvider)
+        : (aiRegistryModel ? [aiRegistryModel] : []);
+    const aiCopilotModel = settings?.ai?.copilotModel
+        ?? (isBuiltInAIProvider(aiProvider) ? getDefaultCopilotModel(aiProvider) : aiModel);
+    const aiCopilotOptions = isBuiltInAIProvider(aiProvider) ? getCopilotModelOptions(aiProvider) : aiModelOptions;
 
     const speechSettings = settings?.ai?.speechToText ?? {};
     const speechProvider = speechSettings.provider ?? 'gemini';
@@ -90,6 +115,15 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
     }, [settings?.ai, showSaved, updateSettings]);
 
     const handleAIProviderChange = useCallback((provider: AIProviderId) => {
+        if (!isBuiltInAIProvider(provider)) {
+            updateAISettings({
+                provider,
+                model: undefined,
+                copilotModel: undefined,
+                thinkingBudget: undefined,
+            });
+            return;
+        }
         updateAISettings({
             provider,
             model: getDefaultAIConfig(provider).model,
@@ -98,6 +132,38 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
         });
     }, [updateAISettings]);
 
+    const handleSaveAIProviders = useCallback(async (next: AIProviderDefinition[]): Promise<boolean> => {
+        setAiProvidersError(null);
+        try {
+            const saved = await saveAIProviders(next);
+            setAiProviders(saved);
+            showSaved();
+            if (!saved.some((provider) => provider.id === aiProvider)) {
+                handleAIProviderChange('openai');
+            }
+            return true;
+        } catch (error) {
+            setAiProvidersError(error instanceof Error ? error.message : String(error));
+            return false;
+        }
+    }, [aiProvider, handleAIProviderChange, showSaved]);
+
+    const handleChooseLocalModel = useCallback(async (): Promise<string | null> => {
+        if (!isTauri) return null;
+        try {
+            const { open } = await import('@tauri-apps/plugin-dialog');
+            const selected = await open({
+                directory: false,
+                multiple: false,
+                filters: [{ name: 'GGUF', extensions: ['gguf'] }],
+            });
+            return typeof selected === 'string' ? selected : null;
+        } catch (error) {
+            reportError('Failed to choose model file', error);
+            return null;
+        }
+    }, [isTauri]);
+
     const handleToggleAnthropicThinking = useCallback(() => {
         updateAISettings({
             thinkingBudget: anthropicThinkingEnabled ? 0 : (DEFAULT_ANTHROPIC_THINKING_BUDGET || 1024),
@@ -106,7 +172,9 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
 
     const handleAiApiKeyChange = useCallback((value: string) => {
         setAiApiKey(value);
-        saveAIKey(aiProvider, value).catch((error) => reportError('Failed to save AI key', error));
+        saveAIKey(aiProvider, value)
+            .then(() => setAiHasKey(Boolean(value)))
+            .catch((error) => reportError('Failed to save AI key', error));
     }, [aiProvider, enabled]);
 
     const handleSpeechProviderChange = useCallback((provider: 'openai' | 'gemini' | 'whisper') => {
@@ -125,7 +193,9 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
     const handleSpeechApiKeyChange = useCallback((value: string) => {
         setSpeechApiKey(value);
         if (speechProvider !== 'whisper') {
-            saveAIKey(speechProvider as AIProviderId, value).catch((error) => reportError('Failed to save speech API key', error));
+            saveAIKey(speechProvider as AIProviderId, value)
+                .then(() => setSpeechHasKey(Boolean(value)))
+                .catch((error) => reportError('Failed to save speech API key', error));
         }
     }, [speechProvider, enabled]);
 
@@ -137,6 +207,23 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
         return await join(base, 'mindwtr', 'whisper-models', entry.fileName);
     }, [isTauri]);
 
+    useEffect(() => {
+        let active = true;
+        if (!enabled || !isTauri) {
+            return () => {
+                active = false;
+            };
+        }
+        getAIProviders()
+            .then((providers) => {
+                if (active) setAiProviders(providers);
+            })
+            .catch((error) => reportError('Failed to load AI providers', error));
+        return () => {
+            active = false;
+        };
+    }, [enabled, isTauri]);
+
     useEffect(() => {
         let active = true;
         if (!enabled) {
@@ -144,17 +231,21 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
                 active = false;
             };
         }
-        loadAIKey(aiProvider)
-            .then((key) => {
-                if (active) setAiApiKey(key);
+        Promise.all([loadAIKey(aiProvider), hasAIKey(aiProvider)])
+            .then(([key, hasKey]) => {
+                if (!active) return;
+                setAiApiKey(key);
+                setAiHasKey(hasKey);
             })
             .catch(() => {
-                if (active) setAiApiKey('');
+                if (!active) return;
+                setAiApiKey('');
+                setAiHasKey(false);
             });
         return () => {
             active = false;
         };
-    }, [aiProvider]);
+    }, [aiProvider, aiProviders]);
 
     useEffect(() => {
         let active = true;
@@ -165,16 +256,21 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
         }
         if (speechProvider === 'whisper') {
             setSpeechApiKey('');
+            setSpeechHasKey(false);
             return () => {
                 active = false;
             };
         }
-        loadAIKey(speechProvider as AIProviderId)
-            .then((key) => {
-                if (active) setSpeechApiKey(key);
+        Promise.all([loadAIKey(speechProvider as AIProviderId), hasAIKey(speechProvider as AIProviderId)])
+            .then(([key, hasKey]) => {
+                if (!active) return;
+                setSpeechApiKey(key);
+                setSpeechHasKey(hasKey);
             })
             .catch(() => {
-                if (active) setSpeechApiKey('');
+                if (!active) return;
+                setSpeechApiKey('');
+                setSpeechHasKey(false);
             });
         return () => {
             active = false;
@@ -313,6 +409,10 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
         aiThinkingBudget,
         anthropicThinkingEnabled,
         aiApiKey,
+        aiHasKey,
+        aiProviders,
+        aiProvidersError,
+        aiKeyless,
         speechEnabled,
         speechProvider,
         speechModel,
@@ -321,6 +421,7 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
         speechMode,
         speechFieldStrategy,
         speechApiKey,
+        speechHasKey,
         speechOfflineReady: Boolean(speechOfflineSize),
         speechOfflineSize,
         speechDownloadState,
@@ -331,6 +432,8 @@ export function useAiSettings({ isTauri, settings, updateSettings, showSaved, en
         onSpeechProviderChange: handleSpeechProviderChange,
         onToggleAnthropicThinking: handleToggleAnthropicThinking,
         onAiApiKeyChange: handleAiApiKeyChange,
+        onSaveAIProviders: handleSaveAIProviders,
+        onChooseLocalModel: handleChooseLocalModel,
         onSpeechApiKeyChange: handleSpeechApiKeyChange,
         onDownloadWhisperModel: handleDownloadWhisperModel,
         onDeleteWhisperModel: handleDeleteWhisperModel,
@@ -1,10 +1,21 @@
 import { useCallback, useEffect, useState } from 'react';
-import { SyncService, type CloudProvider } from '../../../lib/sync-service';
+import type { SyncPreview } from '@mindwtr/core';
+import {
+    SyncService,
+    type CloudProvider,
+    type SecretVaultStatus,
+    type SyncEncryptionStatus,
+    type SyncFileLayout,
+    type SyncJournal,
+} from '../../../lib/sync-service';
 import { useUiStore } from '../../../store/ui-store';
 import { logError } from '../../../lib/app-log';
 
-export type SyncBackend = 'off' | 'file' | 'webdav' | 'cloud';
+export type SyncBackend = 'off' | 'file' | 'webdav' | 'cloud' | 's3' | 'git' | 'sftp';
 export type DropboxTestState = 'idle' | 'success' | 'error';
+export type S3TestState = 'idle' | 'success' | 'error';
+export type GitTestState = 'idle' | 'success' | 'error';
+export type SftpTestState = 'idle' | 'success' | 'error';
 
 type UseSyncSettingsOptions = {
     isTauri: boolean;
@@ -14,6 +25,11 @@ type UseSyncSettingsOptions = {
 
 export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: UseSyncSettingsOptions) => {
     const [syncPath, setSyncPath] = useState('');
+    const [syncFileLayout, setSyncFileLayout] = useState<SyncFileLayout>('single');
+    const [syncFileLayoutBusy, setSyncFileLayoutBusy] = useState(false);
+    const [syncIntervalMinutes, setSyncIntervalMinutes] = useState(0);
+    const [syncPreview, setSyncPreview] = useState<SyncPreview | null>(null);
+    const [syncPreviewBusy, setSyncPreviewBusy] = useState(false);
     const [syncStatus, setSyncStatus] = useState(() => SyncService.getSyncStatus());
     const [syncError, setSyncError] = useState<string | null>(null);
     const [syncBackend, setSyncBackend] = useState<SyncBackend>('off');
@@ -24,6 +40,7 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
     const [isSavingWebDav, setIsSavingWebDav] = useState(false);
     const [cloudUrl, setCloudUrl] = useState('');
     const [cloudToken, setCloudToken] = useState('');
+    const [cloudHasToken, setCloudHasToken] = useState(false);
     const [cloudProvider, setCloudProvider] = useState<CloudProvider>('selfhosted');
     const [dropboxAppKey, setDropboxAppKey] = useState('');
     const [dropboxConfigured, setDropboxConfigured] = useState(false);
@@ -31,9 +48,44 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
     const [dropboxBusy, setDropboxBusy] = useState(false);
     const [dropboxRedirectUri, setDropboxRedirectUri] = useState('http://127.0.0.1:53682/oauth/dropbox/callback');
     const [dropboxTestState, setDropboxTestState] = useState<DropboxTestState>('idle');
+    const [s3Endpoint, setS3Endpoint] = useState('');
+    const [s3Bucket, setS3Bucket] = useState('');
+    const [s3Prefix, setS3Prefix] = useState('');
+    const [s3Region, setS3Region] = useState('');
+    const [s3AccessKeyId, setS3AccessKeyId] = useState('');
+    const [s3SecretKey, setS3SecretKey] = useState('');
+    const [s3HasSecretKey, setS3HasSecretKey] = useState(false);
+    const [s3Busy, setS3Busy] = useState(false);
+    const [s3TestState, setS3TestState] = useState<S3TestState>('idle');
+    const [sftpHost, setSftpHost] = useState('');
+    const [sftpPort, setSftpPort] = useState('');
+    const [sftpUsername, setSftpUsername] = useState('');
+    const [sftpRemotePath, setSftpRemotePath] = useState('');
+    const [sftpKeyPath, setSftpKeyPath] = useState('');
+    const [sftpKeyPassphrase, setSftpKeyPassphrase] = useState('');
+    const [sftpHasKeyPassphrase, setSftpHasKeyPassphrase] = useState(false);
+    const [sftpBusy, setSftpBusy] = useState(false);
+    const [sftpTestState, setSftpTestState] = useState<SftpTestState>('idle');
+    const [gitRepoPath, setGitRepoPath] = useState('');
+    const [gitRemote, setGitRemote] = useState('');
+    const [gitBranch, setGitBranch] = useState('');
+    const [gitBusy, setGitBusy] = useState(false);
+    const [gitTestState, setGitTestState] = useState<GitTestState>('idle');
     const [snapshots, setSnapshots] = useState<string[]>([]);
     const [isLoadingSnapshots, setIsLoadingSnapshots] = useState(false);
     const [isRestoringSnapshot, setIsRestoringSnapshot] = useState(false);
+    const [syncJournal, setSyncJournal] = useState<SyncJournal>({ entries: [], lastSuccessAt: null });
+    const [secretVault, setSecretVault] = useState<SecretVaultStatus | null>(null);
+    const [secretVaultPassphrase, setSecretVaultPassphrase] = useState('');
+    const [secretVaultPassphraseConfirm, setSecretVaultPassphraseConfirm] = useState('');
+    const [secretVaultBusy, setSecretVaultBusy] = useState(false);
+    const [managedConfigKeys, setManagedConfigKeys] = useState<Record<string, string>>({});
+    // Bumped when the config files change outside the app, to read the settings again.
+    const [configVersion, setConfigVersion] = useState(0);
+    const [syncEncryption, setSyncEncryption] = useState<SyncEncryptionStatus>({ enabled: false, keyId: null, rotationPending: false });
+    const [syncEncryptionPassphrase, setSyncEncryptionPassphrase] = useState('');
+    const [syncEncryptionPassphraseConfirm, setSyncEncryptionPassphraseConfirm] = useState('');
+    const [syncEncryptionBusy, setSyncEncryptionBusy] = useState(false);
     const showToast = useUiStore((state) => state.showToast);
 
     const formatSyncPathError = useCallback((message?: string): string => {
@@ -70,6 +122,16 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
                 setSyncError('Failed to load sync path.');
                 void logError(error, { scope: 'sync', step: 'loadPath' });
             });
+        SyncService.getSyncFileLayout()
+            .then(setSyncFileLayout)
+            .catch((error) => {
+                void logError(error, { scope: 'sync', step: 'loadFileLayout' });
+            });
+        SyncService.getSyncInterval()
+            .then(setSyncIntervalMinutes)
+            .catch((error) => {
+                void logError(error, { scope: 'sync', step: 'loadInterval' });
+            });
         SyncService.getSyncBackend()
             .then(setSyncBackend)
             .catch((error) => {
@@ -90,12 +152,49 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
         SyncService.getCloudConfig({ silent: true })
             .then((cfg) => {
                 setCloudUrl(cfg.url);
-                setCloudToken(cfg.token);
+                setCloudToken(cfg.token ?? '');
+                setCloudHasToken(cfg.hasToken === true || Boolean(cfg.token));
             })
             .catch((error) => {
                 setSyncError('Failed to load Cloud config.');
                 void logError(error, { scope: 'sync', step: 'loadCloud' });
             });
+        SyncService.getS3Config({ silent: true })
+            .then((cfg) => {
+                setS3Endpoint(cfg.endpoint);
+                setS3Bucket(cfg.bucket);
+                setS3Prefix(cfg.prefix);
+                setS3Region(cfg.region);
+                setS3AccessKeyId(cfg.accessKeyId);
+                setS3HasSecretKey(cfg.hasSecretKey === true);
+            })
+            .catch((error) => {
+                setSyncError('Failed to load S3 config.');
+                void logError(error, { scope: 'sync', step: 'loadS3' });
+            });
+        SyncService.getSftpConfig({ silent: true })
+            .then((cfg) => {
+                setSftpHost(cfg.host);
+                setSftpPort(cfg.port);
+                setSftpUsername(cfg.username);
+                setSftpRemotePath(cfg.remotePath);
+                setSftpKeyPath(cfg.keyPath);
+                setSftpHasKeyPassphrase(cfg.hasKeyPassphrase === true);
+            })
+            .catch((error) => {
+                setSyncError('Failed to load SFTP config.');
+                void logError(error, { scope: 'sync', step: 'loadSftp' });
+            });
+        SyncService.getGitConfig({ silent: true })
+            .then((cfg) => {
+                setGitRepoPath(cfg.repoPath);
+                setGitRemote(cfg.remote);
+                setGitBranch(cfg.branch);
+            })
+            .catch((error) => {
+                setSyncError('Failed to load Git config.');
+                void logError(error, { scope: 'sync', step: 'loadGit' });
+            });
         SyncService.getCloudProvider()
             .then(setCloudProvider)
             .catch((error) => {
@@ -118,11 +217,45 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
             .catch((error) => {
                 void logError(error, { scope: 'sync', step: 'loadDropboxRedirectUri' });
             });
+        SyncService.getSecretVaultStatus()
+            .then(setSecretVault)
+            .catch((error) => {
+                void logError(error, { scope: 'sync', step: 'loadSecretVault' });
+            });
+        SyncService.getManagedConfigKeys()
+            .then(setManagedConfigKeys)
+            .catch((error) => {
+                void logError(error, { scope: 'sync', step: 'loadManagedConfigKeys' });
+            });
+        SyncService.getSyncEncryptionStatus()
+            .then(setSyncEncryption)
+            .catch((error) => {
+                void logError(error, { scope: 'sync', step: 'loadEncryption' });
+            });
         loadSnapshots().catch((error) => {
             void logError(error, { scope: 'sync', step: 'loadSnapshots' });
         });
         return unsubscribe;
-    }, [isTauri]);
+    }, [isTauri, configVersion]);
+
+    useEffect(() => SyncService.subscribeConfigChange(() => {
+        setConfigVersion((version) => version + 1);
+    }), []);
+
+    useEffect(() => {
+        if (!isTauri || syncStatus.inFlight) return;
+        let cancelled = false;
+        SyncService.getSyncJournal()
+            .then((journal) => {
+                if (!cancelled) setSyncJournal(journal);
+            })
+            .catch((error) => {
+                void logError(error, { scope: 'sync', step: 'loadJournal' });
+            });
+        return () => {
+            cancelled = true;
+        };
+    }, [isTauri, syncStatus.inFlight]);
 
     useEffect(() => {
         let cancelled = false;
@@ -162,6 +295,7 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
         const result = await SyncService.setSyncPath(syncPath.trim());
         if (result.success) {
             setSyncError(null);
+            setSyncFileLayout(await SyncService.getSyncFileLayout());
             showSaved();
             return;
         }
@@ -186,6 +320,7 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
                 const result = await SyncService.setSyncPath(selected);
                 if (result.success) {
                     setSyncError(null);
+                    setSyncFileLayout(await SyncService.getSyncFileLayout());
                     showSaved();
                     return;
                 }
@@ -199,8 +334,54 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
         }
     }, [formatSyncPathError, isTauri, selectSyncFolderTitle, showSaved, showToast]);
 
+    const runSecretVaultChange = useCallback(async (
+        change: () => Promise<SecretVaultStatus>,
+        fallback: string,
+    ) => {
+        setSecretVaultBusy(true);
+        try {
+            setSecretVault(await change());
+            setSecretVaultPassphrase('');
+            setSecretVaultPassphraseConfirm('');
+            showSaved();
+        } catch (error) {
+            showToast(toErrorMessage(error, fallback), 'error');
+        } finally {
+            setSecretVaultBusy(false);
+        }
+    }, [showSaved, showToast, toErrorMessage]);
+
+    const handleUnlockSecretVault = useCallback(async () => {
+        await runSecretVaultChange(
+            () => SyncService.unlockSecretVault(secretVaultPassphrase, false),
+            'Failed to unlock the secret vault.',
+        );
+    }, [runSecretVaultChange, secretVaultPassphrase]);
+
+    const handleCreateSecretVault = useCallback(async () => {
+        await runSecretVaultChange(
+            () => SyncService.unlockSecretVault(secretVaultPassphrase, true),
+            'Failed to create the secret vault.',
+        );
+    }, [runSecretVaultChange, secretVaultPassphrase]);
+
+    const handleLockSecretVault = useCallback(async () => {
+        await runSecretVaultChange(
+            () => SyncService.lockSecretVault(),
+            'Failed to lock the secret vault.',
+        );
+    }, [runSecretVaultChange]);
+
+    const handleAllowPlaintextSecrets = useCallback(async () => {
+        await runSecretVaultChange(
+            () => SyncService.allowPlaintextSecrets(),
+            'Failed to save the secret storage choice.',
+        );
+    }, [runSecretVaultChange]);
+
     const handleSetSyncBackend = useCallback(async (backend: SyncBackend) => {
         setSyncBackend(backend);
+        setSyncPreview(null);
         await SyncService.setSyncBackend(backend);
         showSaved();
     }, [showSaved]);
@@ -228,13 +409,291 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
     }, [showSaved, webdavPassword, webdavUrl, webdavUsername]);
 
     const handleSaveCloud = useCallback(async () => {
+        const trimmedUrl = cloudUrl.trim();
+        const trimmedToken = cloudToken.trim();
         await SyncService.setCloudConfig({
-            url: cloudUrl.trim(),
-            token: cloudToken.trim(),
+            url: trimmedUrl,
+            token: trimmedToken,
         });
+        if (!trimmedUrl) {
+            setCloudHasToken(false);
+            setCloudToken('');
+        } else if (trimmedToken) {
+            setCloudHasToken(true);
+        }
         showSaved();
     }, [cloudUrl, cloudToken, showSaved]);
 
+    const handleSaveS3 = useCallback(async (): Promise<boolean> => {
+        const trimmedBucket = s3Bucket.trim();
+        const trimmedSecretKey = s3SecretKey.trim();
+        try {
+            await SyncService.setS3Config({
+                endpoint: s3Endpoint.trim(),
+                bucket: trimmedBucket,
+                prefix: s3Prefix.trim(),
+                region: s3Region.trim(),
+                accessKeyId: s3AccessKeyId.trim(),
+                secretKey: trimmedSecretKey,
+            });
+        } catch (error) {
+            const message = toErrorMessage(error, 'Failed to save S3 settings.');
+            setSyncError(message);
+            showToast(message, 'error');
+            return false;
+        }
+        if (!trimmedBucket) {
+            setS3HasSecretKey(false);
+        } else if (trimmedSecretKey) {
+            setS3HasSecretKey(true);
+        }
+        setS3SecretKey('');
+        setS3TestState('idle');
+        showSaved();
+        return true;
+    }, [s3AccessKeyId, s3Bucket, s3Endpoint, s3Prefix, s3Region, s3SecretKey, showSaved, showToast, toErrorMessage]);
+
+    const handleTestS3Connection = useCallback(async () => {
+        if (!s3Bucket.trim()) {
+            showToast('Enter an S3 bucket first.', 'error');
+            return;
+        }
+        setS3Busy(true);
+        try {
+            if (!(await handleSaveS3())) {
+                setS3TestState('error');
+                return;
+            }
+            await SyncService.testS3Connection();
+            setS3TestState('success');
+            showToast('S3 bucket is reachable.', 'success');
+        } catch (error) {
+            const message = toErrorMessage(error, 'S3 connection failed.');
+            setS3TestState('error');
+            setSyncError(message);
+            showToast(message, 'error');
+        } finally {
+            setS3Busy(false);
+        }
+    }, [handleSaveS3, s3Bucket, showToast, toErrorMessage]);
+
+    const handleSaveSftp = useCallback(async (keyPath = sftpKeyPath): Promise<boolean> => {
+        const trimmedHost = sftpHost.trim();
+        const trimmedKeyPath = keyPath.trim();
+        try {
+            await SyncService.setSftpConfig({
+                host: trimmedHost,
+                port: sftpPort.trim(),
+                username: sftpUsername.trim(),
+                remotePath: sftpRemotePath.trim(),
+                keyPath: trimmedKeyPath,
+                keyPassphrase: sftpKeyPassphrase,
+            });
+        } catch (error) {
+            const message = toErrorMessage(error, 'Failed to save SFTP settings.');
+            setSyncError(message);
+            showToast(message, 'error');
+            return false;
+        }
+        if (!trimmedHost || !trimmedKeyPath) {
+            setSftpHasKeyPassphrase(false);
+        } else if (sftpKeyPassphrase) {
+            setSftpHasKeyPassphrase(true);
+        }
+        setSftpKeyPassphrase('');
+        setSftpTestState('idle');
+        showSaved();
+        return true;
+    }, [sftpHost, sftpKeyPassphrase, sftpKeyPath, sftpPort, sftpRemotePath, sftpUsername, showSaved, showToast, toErrorMessage]);
+
+    const handleChooseSftpKey = useCallback(async () => {
+        try {
+            if (!isTauri) return;
+
+            const { open } = await import('@tauri-apps/plugin-dialog');
+            const selected = await open({
+                directory: false,
+                multiple: false,
+            });
+
+            if (selected && typeof selected === 'string') {
+                setSftpKeyPath(selected);
+            }
+        } catch (error) {
+            setSyncError('Failed to choose SSH key.');
+            void logError(error, { scope: 'sync', step: 'chooseSftpKey' });
+        }
+    }, [isTauri]);
+
+    const handleTestSftpConnection = useCallback(async () => {
+        if (!sftpHost.trim() || !sftpUsername.trim()) {
+            showToast('Enter the SFTP host and username first.', 'error');
+            return;
+        }
+        setSftpBusy(true);
+        try {
+            if (!(await handleSaveSftp())) {
+                setSftpTestState('error');
+                return;
+            }
+            await SyncService.testSftpConnection();
+            setSftpTestState('success');
+            showToast('SFTP server is reachable.', 'success');
+        } catch (error) {
+            const message = toErrorMessage(error, 'SFTP connection failed.');
+            setSftpTestState('error');
+            setSyncError(message);
+            showToast(message, 'error');
+        } finally {
+            setSftpBusy(false);
+        }
+    }, [handleSaveSftp, sftpHost, sftpUsername, showToast, toErrorMessage]);
+
+    const handleSetSyncFileLayout = useCallback(async (layout: SyncFileLayout) => {
+        if (layout === syncFileLayout) return;
+        setSyncFileLayoutBusy(true);
+        try {
+            setSyncFileLayout(await SyncService.setSyncFileLayout(layout));
+            setSyncError(null);
+            showSaved();
+        } catch (error) {
+            const message = toErrorMessage(error, 'Failed to convert the sync folder.');
+            setSyncError(message);
+            showToast(message, 'error');
+        } finally {
+            setSyncFileLayoutBusy(false);
+        }
+    }, [showSaved, showToast, syncFileLayout, toErrorMessage]);
+
+    const handleSetSyncIntervalMinutes = useCallback(async (minutes: number) => {
+        if (minutes === syncIntervalMinutes) return;
+        try {
+            setSyncIntervalMinutes(await SyncService.setSyncInterval(minutes));
+            showSaved();
+        } catch (error) {
+            showToast(toErrorMessage(error, 'Failed to save the sync interval.'), 'error');
+        }
+    }, [showSaved, showToast, syncIntervalMinutes, toErrorMessage]);
+
+    const handlePreviewSync = useCallback(async () => {
+        setSyncPreviewBusy(true);
+        try {
+            setSyncPreview(await SyncService.previewSync());
+            setSyncError(null);
+        } catch (error) {
+            setSyncPreview(null);
+            const message = toErrorMessage(error, 'Failed to preview sync.');
+            setSyncError(message);
+            showToast(message, 'error');
+        } finally {
+            setSyncPreviewBusy(false);
+        }
+    }, [showToast, toErrorMessage]);
+
+    const handleSaveGit = useCallback(async (repoPath = gitRepoPath): Promise<boolean> => {
+        try {
+            await SyncService.setGitConfig({
+                repoPath: repoPath.trim(),
+                remote: gitRemote.trim(),
+                branch: gitBranch.trim(),
+            });
+        } catch (error) {
+            const message = toErrorMessage(error, 'Failed to save Git settings.');
+            setSyncError(message);
+            showToast(message, 'error');
+            return false;
+        }
+        setGitTestState('idle');
+        showSaved();
+        return true;
+    }, [gitBranch, gitRemote, gitRepoPath, showSaved, showToast, toErrorMessage]);
+
+    const handleChooseGitRepo = useCallback(async () => {
+        try {
+            if (!isTauri) return;
+
+            const { open } = await import('@tauri-apps/plugin-dialog');
+            const selected = await open({
+                directory: true,
+                multiple: false,
+                title: selectSyncFolderTitle,
+            });
+
+            if (selected && typeof selected === 'string') {
+                setGitRepoPath(selected);
+                await handleSaveGit(selected);
+            }
+        } catch (error) {
+            setSyncError('Failed to choose Git repository.');
+            void logError(error, { scope: 'sync', step: 'chooseGitRepo' });
+        }
+    }, [handleSaveGit, isTauri, selectSyncFolderTitle]);
+
+    const handleTestGitConnection = useCallback(async () => {
+        if (!gitRepoPath.trim()) {
+            showToast('Choose a Git repository first.', 'error');
+            return;
+        }
+        setGitBusy(true);
+        try {
+            if (!(await handleSaveGit())) {
+                setGitTestState('error');
+                return;
+            }
+            await SyncService.testGitConnection();
+            setGitTestState('success');
+            showToast('Git remote is reachable.', 'success');
+        } catch (error) {
+            const message = toErrorMessage(error, 'Git connection failed.');
+            setGitTestState('error');
+            setSyncError(message);
+            showToast(message, 'error');
+        } finally {
+            setGitBusy(false);
+        }
+    }, [gitRepoPath, handleSaveGit, showToast, toErrorMessage]);
+
+    const runSyncEncryptionChange = useCallback(async (
+        change: () => Promise<SyncEncryptionStatus>,
+        fallback: string,
+    ) => {
+        setSyncEncryptionBusy(true);
+        try {
+            setSyncEncryption(await change());
+            setSyncEncryptionPassphrase('');
+            setSyncEncryptionPassphraseConfirm('');
+            setSyncError(null);
+            showSaved();
+        } catch (error) {
+            const message = toErrorMessage(error, fallback);
+            setSyncError(message);
+            showToast(message, 'error');
+        } finally {
+            setSyncEncryptionBusy(false);
+        }
+    }, [showSaved, showToast, toErrorMessage]);
+
+    const handleEnableSyncEncryption = useCallback(async () => {
+        await runSyncEncryptionChange(
+            () => SyncService.enableSyncEncryption(syncEncryptionPassphrase),
+            'Failed to enable sync encryption.',
+        );
+    }, [runSyncEncryptionChange, syncEncryptionPassphrase]);
+
+    const handleRotateSyncEncryptionKey = useCallback(async () => {
+        await runSyncEncryptionChange(
+            () => SyncService.rotateSyncEncryptionKey(syncEncryptionPassphrase),
+            'Failed to change the sync encryption passphrase.',
+        );
+    }, [runSyncEncryptionChange, syncEncryptionPassphrase]);
+
+    const handleDisableSyncEncryption = useCallback(async () => {
+        await runSyncEncryptionChange(
+            () => SyncService.disableSyncEncryption(),
+            'Failed to disable sync encryption.',
+        );
+    }, [runSyncEncryptionChange]);
+
     const handleSetCloudProvider = useCallback(async (provider: CloudProvider) => {
         setCloudProvider(provider);
         if (provider !== 'dropbox') {
@@ -355,6 +814,18 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
                     setDropboxConnected(true);
                 }
             }
+            if (syncBackend === 's3') {
+                if (!s3Bucket.trim()) return;
+                if (!(await handleSaveS3())) return;
+            }
+            if (syncBackend === 'sftp') {
+                if (!sftpHost.trim()) return;
+                if (!(await handleSaveSftp())) return;
+            }
+            if (syncBackend === 'git') {
+                if (!gitRepoPath.trim()) return;
+                if (!(await handleSaveGit())) return;
+            }
             if (syncBackend === 'file') {
                 const path = syncPath.trim();
                 if (path) {
@@ -388,9 +859,15 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
         cloudUrl,
         dropboxAppKey,
         formatSyncPathError,
+        gitRepoPath,
         handleSaveCloud,
+        handleSaveGit,
+        handleSaveS3,
+        handleSaveSftp,
         handleSaveWebDav,
         isTauri,
+        s3Bucket,
+        sftpHost,
         showToast,
         syncBackend,
         syncPath,
@@ -418,6 +895,11 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
     return {
         syncPath,
         setSyncPath,
+        syncFileLayout,
+        syncFileLayoutBusy,
+        syncIntervalMinutes,
+        syncPreview,
+        syncPreviewBusy,
         isSyncing: syncStatus.inFlight,
         syncQueued: syncStatus.queued,
         syncLastResult: syncStatus.lastResult,
@@ -437,6 +919,7 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
         setCloudUrl,
         cloudToken,
         setCloudToken,
+        cloudHasToken,
         cloudProvider,
         setCloudProvider,
         dropboxAppKey,
@@ -445,11 +928,73 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
         dropboxBusy,
         dropboxRedirectUri,
         dropboxTestState,
+        s3Endpoint,
+        setS3Endpoint,
+        s3Bucket,
+        setS3Bucket,
+        s3Prefix,
+        setS3Prefix,
+        s3Region,
+        setS3Region,
+        s3AccessKeyId,
+        setS3AccessKeyId,
+        s3SecretKey,
+        setS3SecretKey,
+        s3HasSecretKey,
+        s3Busy,
+        s3TestState,
+        sftpHost,
+        setSftpHost,
+        sftpPort,
+        setSftpPort,
+        sftpUsername,
+        setSftpUsername,
+        sftpRemotePath,
+        setSftpRemotePath,
+        sftpKeyPath,
+        setSftpKeyPath,
+        sftpKeyPassphrase,
+        setSftpKeyPassphrase,
+        sftpHasKeyPassphrase,
+        sftpBusy,
+        sftpTestState,
+        gitRepoPath,
+        setGitRepoPath,
+        gitRemote,
+        setGitRemote,
+        gitBranch,
+        setGitBranch,
+        gitBusy,
+        gitTestState,
         snapshots,
         isLoadingSnapshots,
         isRestoringSnapshot,
+        syncJournal,
+        secretVault,
+        secretVaultPassphrase,
+        setSecretVaultPassphrase,
+        secretVaultPassphraseConfirm,
+        setSecretVaultPassphraseConfirm,
+        secretVaultBusy,
+        managedConfigKeys,
+        syncEncryption,
+        syncEncryptionPassphrase,
+        setSyncEncryptionPassphrase,
+        syncEncryptionPassphraseConfirm,
+        setSyncEncryptionPassphraseConfirm,
+        syncEncryptionBusy,
         handleSaveSyncPath,
         handleChangeSyncLocation,
+        handleSetSyncFileLayout,
+        handleSetSyncIntervalMinutes,
+        handlePreviewSync,
+        handleUnlockSecretVault,
+        handleCreateSecretVault,
+        handleLockSecretVault,
+        handleAllowPlaintextSecrets,
+        handleEnableSyncEncryption,
+        handleRotateSyncEncryptionKey,
+        handleDisableSyncEncryption,
         handleSetSyncBackend,
         handleSaveWebDav,
         handleSaveCloud,
@@ -457,6 +1002,14 @@ export const useSyncSettings = ({ isTauri, showSaved, selectSyncFolderTitle }: U
         handleConnectDropbox,
         handleDisconnectDropbox,
         handleTestDropboxConnection,
+        handleSaveS3,
+        handleTestS3Connection,
+        handleSaveSftp,
+        handleChooseSftpKey,
+        handleTestSftpConnection,
+        handleSaveGit,
+        handleChooseGitRepo,
+        handleTestGitConnection,
         handleSync,
         handleRestoreSnapshot,
     };
@@ -1,6 +1,21 @@
-import { describe, expect, it } from 'vitest';
+import { afterEach, describe, expect, it, vi } from 'vitest';
 import type { AppData } from '@mindwtr/core';
-import { isAIKeyRequired } from './ai-config';
+import { createAIProvider } from '@mindwtr/core';
+
+const mocks = vi.hoisted(() => ({
+    invoke: vi.fn(),
+    isTauri: true,
+}));
+
+vi.mock('@tauri-apps/api/core', () => ({
+    invoke: mocks.invoke,
+}));
+
+vi.mock('./runtime', () => ({
+    isTauriRuntime: () => mocks.isTauri,
+}));
+
+import { buildAIConfig, getAIProviders, hasAIKey, isAIKeyRequired, loadAIKey, saveAIProviders } from './ai-config';
 
 const createSettings = (ai: AppData['settings']['ai']): AppData['settings'] => ({ ai });
 
@@ -12,12 +27,25 @@ describe('isAIKeyRequired', () => {
         }))).toBe(true);
     });
 
-    it('does not require key for custom OpenAI-compatible endpoint', () => {
+    it('does not require key for custom OpenAI-compatible endpoint in the browser', () => {
+        mocks.isTauri = false;
+        try {
+            expect(isAIKeyRequired(createSettings({
+                provider: 'openai',
+                model: 'llama3.2',
+                baseUrl: 'http://localhost:11434/v1',
+            }))).toBe(false);
+        } finally {
+            mocks.isTauri = true;
+        }
+    });
+
+    it('requires key for OpenAI in the desktop app, which ignores the custom base URL', () => {
         expect(isAIKeyRequired(createSettings({
             provider: 'openai',
             model: 'llama3.2',
             baseUrl: 'http://localhost:11434/v1',
-        }))).toBe(false);
+        }))).toBe(true);
     });
 
     it('requires key for non-openai providers', () => {
@@ -26,4 +54,68 @@ describe('isAIKeyRequired', () => {
             model: 'gemini-2.5-flash',
         }))).toBe(true);
     });
+
+    it('leaves the key of registry providers to the backend', () => {
+        expect(isAIKeyRequired(createSettings({ provider: 'ollama' }))).toBe(false);
+    });
+});
+
+describe('desktop AI requests', () => {
+    afterEach(() => {
+        mocks.invoke.mockReset();
+    });
+
+    it('keeps the key in the backend and only reports whether one is stored', async () => {
+        mocks.invoke.mockResolvedValue(true);
+        expect(await loadAIKey('openai')).toBe('');
+        expect(await hasAIKey('openai')).toBe(true);
+        expect(mocks.invoke).toHaveBeenCalledWith('has_ai_key', { provider: 'openai' });
+    });
+
+    it('sends AI features through the ai_chat command', async () => {
+        mocks.invoke.mockResolvedValue({ text: '{"steps":["Book flights"]}', cancelled: false });
+        const provider = createAIProvider(buildAIConfig(createSettings({
+            provider: 'openai',
+            model: 'llama3.2',
+            baseUrl: 'http://localhost:11434/v1',
+        }), ''));
+
+        const result = await provider.breakDownTask({ title: 'Plan trip' });
+
+        expect(result.steps).toEqual(['Book flights']);
+        const [command, args] = mocks.invoke.mock.calls[0] as [string, Record<string, any>];
+        expect(command).toBe('ai_chat');
+        expect(args.provider).toBe('openai');
+        expect(args.model).toBe('llama3.2');
+        expect(args.messages.map((message: { role: string }) => message.role)).toEqual(['system', 'user']);
+        expect(args.options).toMatchObject({ jsonMode: true });
+        expect(args.options).not.toHaveProperty('baseUrl');
+        expect(JSON.stringify(args)).not.toContain('apiKey');
+    });
+
+    it('lists and saves registry providers through the backend', async () => {
+        const ollama = { id: 'ollama', name: 'Ollama', kind: 'openai-compatible' as const, baseUrl: 'http://localhost:11434/v1' };
+        mocks.invoke.mockResolvedValue([{ ...ollama, builtIn: false, configured: true, hasKey: false }]);
+
+        expect((await getAIProviders())[0].id).toBe('ollama');
+        expect(mocks.invoke).toHaveBeenCalledWith('get_ai_providers');
+        await saveAIProviders([ollama]);
+        expect(mocks.invoke).toHaveBeenCalledWith('set_ai_providers', { providers: [ollama] });
+    });
+
+    it('cancels the backend request when the caller aborts', async () => {
+        mocks.invoke.mockImplementation((command: string) => (
+            command === 'ai_chat' ? new Promise(() => undefined) : Promise.resolve(true)
+        ));
+        const provider = createAIProvider(buildAIConfig(createSettings({ provider: 'gemini', model: 'gemini-2.5-flash' }), ''));
+        const controller = new AbortController();
+
+        const pending = provider.clarifyTask({ title: 'Plan trip' }, { signal: controller.signal });
+        await vi.waitFor(() => expect(mocks.invoke).toHaveBeenCalledWith('ai_chat', expect.anything()));
+        controller.abort();
+
+        await expect(pending).rejects.toThrow('AI request aborted');
+        const requestId = (mocks.invoke.mock.calls[0] as [string, { requestId: string }])[1].requestId;
+        expect(mocks.invoke).toHaveBeenCalledWith('cancel_ai_request', { requestId });
+    });
 });
@@ -1,5 +1,18 @@
-import type { AIProviderId, AppData } from '@mindwtr/core';
-import { buildAIConfig, buildCopilotConfig, getAIKeyStorageKey } from '@mindwtr/core';
+import type {
+    AIChatTransport,
+    AIProviderConfig,
+    AIProviderDefinition,
+    AIProviderId,
+    AIProviderInfo,
+    AppData,
+} from '@mindwtr/core';
+import {
+    buildAIConfig as buildCoreAIConfig,
+    buildCopilotConfig as buildCoreCopilotConfig,
+    generateUUID,
+    getAIKeyStorageKey,
+    isBuiltInAIProvider,
+} from '@mindwtr/core';
 import { isTauriRuntime } from './runtime';
 import { logError } from './app-log';
 
@@ -101,18 +114,26 @@ const saveLocalKey = async (provider: AIProviderId, value: string): Promise<void
     localStorage.setItem(key, `${bytesToBase64(iv)}:${bytesToBase64(payload)}`);
 };
 
+/**
+ * The key for calling a provider from the webview. The desktop app keeps keys in the backend,
+ * which makes the calls itself, so this is always empty there; use `hasAIKey` to check for one.
+ */
 export async function loadAIKey(provider: AIProviderId): Promise<string> {
+    if (isTauriRuntime()) return '';
+    return await loadLocalKey(provider);
+}
+
+export async function hasAIKey(provider: AIProviderId): Promise<boolean> {
     if (isTauriRuntime()) {
         try {
             const { invoke } = await import('@tauri-apps/api/core');
-            const value = await invoke<string | null>('get_ai_key', { provider });
-            if (typeof value === 'string') return value;
+            return await invoke<boolean>('has_ai_key', { provider });
         } catch (error) {
-            void logError(error, { scope: 'ai', step: 'loadKey' });
-            return '';
+            void logError(error, { scope: 'ai', step: 'hasKey' });
+            return false;
         }
     }
-    return await loadLocalKey(provider);
+    return Boolean(await loadLocalKey(provider));
 }
 
 export async function saveAIKey(provider: AIProviderId, value: string): Promise<void> {
@@ -130,8 +151,84 @@ export async function saveAIKey(provider: AIProviderId, value: string): Promise<
 }
 
 export function isAIKeyRequired(settings: AppData['settings'] | undefined): boolean {
+    // Registry providers may be keyless local servers or on-device models; the backend reports a missing key.
+    if (!isBuiltInAIProvider(settings?.ai?.provider ?? 'openai')) return false;
+    // The backend only calls a built-in provider at its own endpoint, which always takes a key.
+    if (isTauriRuntime()) return true;
     const config = buildAIConfig(settings ?? {}, '');
     return !(config.provider === 'openai' && Boolean(config.endpoint));
 }
 
-export { buildAIConfig, buildCopilotConfig };
+/** Built-in and custom providers from the desktop registry in config.toml. */
+export async function getAIProviders(): Promise<AIProviderInfo[]> {
+    if (!isTauriRuntime()) return [];
+    const { invoke } = await import('@tauri-apps/api/core');
+    return await invoke<AIProviderInfo[]>('get_ai_providers');
+}
+
+/**
+ * Replaces the providers defined in config.toml. Entries with a built-in id redefine that
+ * provider; built-ins left out keep their defaults.
+ */
+export async function saveAIProviders(providers: AIProviderDefinition[]): Promise<AIProviderInfo[]> {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return await invoke<AIProviderInfo[]>('set_ai_providers', { providers });
+}
+
+type AIChatResult = {
+    text: string;
+    cancelled: boolean;
+};
+
+/** Runs AI chat requests through the backend `ai_chat` command, which adds the stored key. */
+const invokeAIChat: AIChatTransport = async (request) => {
+    const { invoke } = await import('@tauri-apps/api/core');
+    if (request.signal?.aborted) {
+        throw new Error('AI request aborted');
+    }
+    const requestId = generateUUID();
+    let rejectAborted: (error: Error) => void = () => undefined;
+    const aborted = new Promise<never>((_, reject) => {
+        rejectAborted = reject;
+    });
+    const onAbort = () => {
+        invoke('cancel_ai_request', { requestId }).catch(() => undefined);
+        rejectAborted(new Error('AI request aborted'));
+    };
+    request.signal?.addEventListener('abort', onAbort, { once: true });
+    const chat = invoke<AIChatResult>('ai_chat', {
+        requestId,
+        provider: request.provider,
+        model: request.model,
+        messages: [
+            { role: 'system', content: request.system },
+            { role: 'user', content: request.user },
+        ],
+        options: {
+            temperature: 0.2,
+            jsonMode: true,
+            timeoutSecs: request.timeoutMs ? Math.ceil(request.timeoutMs / 1000) : undefined,
+        },
+    });
+    try {
+        const result = await Promise.race([chat, aborted]);
+        if (result.cancelled) {
+            throw new Error('AI request aborted');
+        }
+        return result.text;
+    } finally {
+        request.signal?.removeEventListener('abort', onAbort);
+    }
+};
+
+const withDesktopTransport = (config: AIProviderConfig): AIProviderConfig => (
+    isTauriRuntime() ? { ...config, transport: invokeAIChat } : config
+);
+
+export function buildAIConfig(settings: AppData['settings'], apiKey: string): AIProviderConfig {
+    return withDesktopTransport(buildCoreAIConfig(settings, apiKey));
+}
+
+export function buildCopilotConfig(settings: AppData['settings'], apiKey: string): AIProviderConfig {
+    return withDesktopTransport(buildCoreCopilotConfig(settings, apiKey));
+}
@@ -5,17 +5,9 @@ import { fetchSystemCalendarEvents } from './system-calendar';
 
 async function fetchTextWithTimeout(url: string, timeoutMs: number): Promise<string> {
     if (isTauriRuntime()) {
-        const mod: any = await import('@tauri-apps/plugin-http');
-        const tauriFetch: any = mod.fetch;
-        const controller = new AbortController();
-        const timeout = setTimeout(() => controller.abort(), timeoutMs);
-        try {
-            const res = await tauriFetch(url, { method: 'GET', signal: controller.signal });
-            if (!res.ok) throw new Error(`HTTP ${res.status}`);
-            return await res.text();
-        } finally {
-            clearTimeout(timeout);
-        }
+        // Fetched by the backend so the configured proxy, CA certificates and timeouts apply.
+        const { invoke } = await import('@tauri-apps/api/core');
+        return await invoke<string>('fetch_calendar_text', { url });
     }
 
     const controller = typeof AbortController !== 'undefined' ? new AbortController() : null;
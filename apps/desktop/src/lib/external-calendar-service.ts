@@ -3,6 +3,7 @@ import { isTauriRuntime } from './runtime';
 import { reportError } from './report-error';
 
 const EXTERNAL_CALENDARS_KEY = 'mindwtr-external-calendars';
+const calendarListeners = new Set<() => void>();
 
 async function tauriInvoke<T>(command: string, args?: Record<string, unknown>): Promise<T> {
     const mod = await import('@tauri-apps/api/core');
@@ -31,6 +32,16 @@ function sanitizeCalendars(calendars: ExternalCalendarSubscription[]): ExternalC
 }
 
 export class ExternalCalendarService {
+    static subscribe(listener: () => void): () => void {
+        calendarListeners.add(listener);
+        return () => calendarListeners.delete(listener);
+    }
+
+    /** Tell open views the calendar list changed outside the app, e.g. in the config file. */
+    static notifyChanged(): void {
+        calendarListeners.forEach((listener) => listener());
+    }
+
     static async getCalendars(): Promise<ExternalCalendarSubscription[]> {
         if (!isTauriRuntime()) {
             return sanitizeCalendars(safeJsonParse(localStorage.getItem(EXTERNAL_CALENDARS_KEY), []));
@@ -42,11 +42,24 @@ type FetchOptions = {
     signal?: AbortSignal;
 };
 
+type FormField = {
+    name: string;
+    value?: string;
+    fileName?: string;
+    mimeType?: string;
+    bytes?: Uint8Array;
+};
+
+type ProviderRequest = {
+    json?: unknown;
+    form?: FormField[];
+};
+
 const DEFAULT_TIMEOUT_MS = 30_000;
-const OPENAI_TRANSCRIBE_URL = 'https://api.openai.com/v1/audio/transcriptions';
-const OPENAI_CHAT_URL = 'https://api.openai.com/v1/chat/completions';
-const OPENAI_RESPONSES_URL = 'https://api.openai.com/v1/responses';
-const GEMINI_BASE_URL = 'https://generativelanguage.googleapis.com/v1beta/models';
+const PROVIDER_BASE_URLS: Record<Exclude<SpeechProvider, 'whisper'>, string> = {
+    openai: 'https://api.openai.com/v1',
+    gemini: 'https://generativelanguage.googleapis.com/v1beta',
+};
 
 const bytesToBase64 = (bytes: Uint8Array) => {
     const alphabet = 'ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/';
@@ -165,34 +178,84 @@ const fetchJson = async (url: string, init: RequestInit, options?: FetchOptions)
     }
 };
 
-const transcribeOpenAI = async (audio: AudioInput, config: SpeechToTextConfig) => {
-    if (!config.apiKey) {
-        throw new Error('OpenAI API key missing');
-    }
-    const bytes = new Uint8Array(audio.bytes);
-    const blob = new Blob([bytes], { type: audio.mimeType });
-    const fileName = audio.name || 'audio.wav';
-    const file = new File([blob], fileName, { type: audio.mimeType });
+const toFormData = (fields: FormField[]) => {
     const form = new FormData();
-    form.append('file', file);
-    form.append('model', config.model);
-    const language = resolveLanguage(config.language);
-    if (language !== 'auto') {
-        form.append('language', language);
+    for (const field of fields) {
+        if (field.bytes) {
+            const blob = new Blob([new Uint8Array(field.bytes)], { type: field.mimeType });
+            form.append(field.name, new File([blob], field.fileName || field.name, { type: field.mimeType }));
+        } else {
+            form.append(field.name, field.value ?? '');
+        }
     }
-    form.append('response_format', 'json');
+    return form;
+};
 
-    const result = await fetchJson(
-        OPENAI_TRANSCRIBE_URL,
+/**
+ * Posts to a provider API. In the desktop app the backend sends the request with the stored key,
+ * which never reaches the webview; elsewhere the key comes from the config.
+ */
+const requestProvider = async (
+    provider: Exclude<SpeechProvider, 'whisper'>,
+    path: string[],
+    request: ProviderRequest,
+    config: SpeechToTextConfig
+) => {
+    if (isTauriRuntime()) {
+        const { invoke } = await import('@tauri-apps/api/core');
+        return invoke<unknown>('ai_provider_request', {
+            provider,
+            path,
+            body: request.form ? null : request.json,
+            form: request.form?.map((field) => ({
+                name: field.name,
+                value: field.value ?? null,
+                fileName: field.fileName ?? null,
+                mimeType: field.mimeType ?? null,
+                dataBase64: field.bytes ? bytesToBase64(field.bytes) : null,
+            })) ?? null,
+            timeoutSecs: Math.ceil(DEFAULT_TIMEOUT_MS / 1000),
+        });
+    }
+    if (!config.apiKey) {
+        throw new Error(provider === 'gemini' ? 'Gemini API key missing' : 'OpenAI API key missing');
+    }
+    const segments = path.map((segment) => encodeURIComponent(segment).replace(/%3A/gi, ':'));
+    const url = `${PROVIDER_BASE_URLS[provider]}/${segments.join('/')}`;
+    const headers: Record<string, string> = provider === 'gemini'
+        ? { 'x-goog-api-key': config.apiKey }
+        : { Authorization: `Bearer ${config.apiKey}` };
+    if (request.form) {
+        return fetchJson(url, { method: 'POST', headers, body: toFormData(request.form) }, { timeoutMs: DEFAULT_TIMEOUT_MS });
+    }
+    return fetchJson(
+        url,
         {
             method: 'POST',
-            headers: {
-                Authorization: `Bearer ${config.apiKey}`,
-            },
-            body: form,
+            headers: { ...headers, 'Content-Type': 'application/json' },
+            body: JSON.stringify(request.json),
         },
         { timeoutMs: DEFAULT_TIMEOUT_MS }
     );
+};
+
+const transcribeOpenAI = async (audio: AudioInput, config: SpeechToTextConfig) => {
+    const form: FormField[] = [
+        {
+            name: 'file',
+            fileName: audio.name || 'audio.wav',
+            mimeType: audio.mimeType,
+            bytes: audio.bytes,
+        },
+        { name: 'model', value: config.model },
+    ];
+    const language = resolveLanguage(config.language);
+    if (language !== 'auto') {
+        form.push({ name: 'language', value: language });
+    }
+    form.push({ name: 'response_format', value: 'json' });
+
+    const result = await requestProvider('openai', ['audio', 'transcriptions'], { form }, config);
     const text = typeof (result as { text?: unknown }).text === 'string'
         ? (result as { text: string }).text
         : '';
@@ -224,9 +287,6 @@ const extractResponsesText = (result: unknown) => {
 };
 
 const parseWithOpenAIResponses = async (transcript: string, config: SpeechToTextConfig, overrideModel?: string) => {
-    if (!config.apiKey) {
-        throw new Error('OpenAI API key missing');
-    }
     const now = config.now ?? new Date();
     const prompt = buildSmartPrompt({
         fieldStrategy: config.fieldStrategy ?? 'smart',
@@ -235,26 +295,17 @@ const parseWithOpenAIResponses = async (transcript: string, config: SpeechToText
         timeZone: config.timeZone,
     });
     const parserModel = resolveOpenAIParseModel(overrideModel ?? config.parseModel);
-    const result = await fetchJson(
-        OPENAI_RESPONSES_URL,
-        {
-            method: 'POST',
-            headers: {
-                'Content-Type': 'application/json',
-                Authorization: `Bearer ${config.apiKey}`,
-            },
-            body: JSON.stringify({
-                model: parserModel,
-                temperature: 0.2,
-                input: [
-                    { role: 'system', content: prompt },
-                    { role: 'user', content: transcript },
-                ],
-                text: { format: { type: 'json_object' } },
-            }),
+    const result = await requestProvider('openai', ['responses'], {
+        json: {
+            model: parserModel,
+            temperature: 0.2,
+            input: [
+                { role: 'system', content: prompt },
+                { role: 'user', content: transcript },
+            ],
+            text: { format: { type: 'json_object' } },
         },
-        { timeoutMs: DEFAULT_TIMEOUT_MS }
-    );
+    }, config);
     const content = extractResponsesText(result);
     if (!content) {
         throw new Error('OpenAI returned no content.');
@@ -263,9 +314,6 @@ const parseWithOpenAIResponses = async (transcript: string, config: SpeechToText
 };
 
 const parseWithOpenAIChat = async (transcript: string, config: SpeechToTextConfig, overrideModel?: string) => {
-    if (!config.apiKey) {
-        throw new Error('OpenAI API key missing');
-    }
     const now = config.now ?? new Date();
     const prompt = buildSmartPrompt({
         fieldStrategy: config.fieldStrategy ?? 'smart',
@@ -274,26 +322,17 @@ const parseWithOpenAIChat = async (transcript: string, config: SpeechToTextConfi
         timeZone: config.timeZone,
     });
     const parserModel = resolveOpenAIParseModel(overrideModel ?? config.parseModel);
-    const result = await fetchJson(
-        OPENAI_CHAT_URL,
-        {
-            method: 'POST',
-            headers: {
-                'Content-Type': 'application/json',
-                Authorization: `Bearer ${config.apiKey}`,
-            },
-            body: JSON.stringify({
-                model: parserModel,
-                temperature: 0.2,
-                response_format: { type: 'json_object' },
-                messages: [
-                    { role: 'system', content: prompt },
-                    { role: 'user', content: transcript },
-                ],
-            }),
+    const result = await requestProvider('openai', ['chat', 'completions'], {
+        json: {
+            model: parserModel,
+            temperature: 0.2,
+            response_format: { type: 'json_object' },
+            messages: [
+                { role: 'system', content: prompt },
+                { role: 'user', content: transcript },
+            ],
         },
-        { timeoutMs: DEFAULT_TIMEOUT_MS }
-    );
+    }, config);
     const content = (result as { choices?: Array<{ message?: { content?: string } }> }).choices?.[0]?.message?.content;
     if (!content) {
         throw new Error('OpenAI returned no content.');
@@ -314,9 +353,6 @@ const parseWithOpenAI = async (transcript: string, config: SpeechToTextConfig, o
 };
 
 const requestGemini = async (audio: AudioInput, config: SpeechToTextConfig, promptOverride?: string) => {
-    if (!config.apiKey) {
-        throw new Error('Gemini API key missing');
-    }
     const now = config.now ?? new Date();
     const prompt = promptOverride ?? buildSmartPrompt({
         fieldStrategy: config.fieldStrategy ?? 'smart',
@@ -325,7 +361,6 @@ const requestGemini = async (audio: AudioInput, config: SpeechToTextConfig, prom
         timeZone: config.timeZone,
     });
     const base64Audio = bytesToBase64(audio.bytes);
-    const url = `${GEMINI_BASE_URL}/${config.model}:generateContent`;
     const body = {
         contents: [
             {
@@ -350,18 +385,7 @@ const requestGemini = async (audio: AudioInput, config: SpeechToTextConfig, prom
             responseMimeType: 'application/json',
         },
     };
-    const result = await fetchJson(
-        url,
-        {
-            method: 'POST',
-            headers: {
-                'Content-Type': 'application/json',
-                'x-goog-api-key': config.apiKey,
-            },
-            body: JSON.stringify(body),
-        },
-        { timeoutMs: DEFAULT_TIMEOUT_MS }
-    );
+    const result = await requestProvider('gemini', ['models', `${config.model}:generateContent`], { json: body }, config);
     const text = (result as { candidates?: Array<{ content?: { parts?: Array<{ text?: string }> } }> })
         .candidates?.[0]?.content?.parts?.[0]?.text;
     if (!text) {
@@ -64,6 +64,38 @@ export const reportProgress = (
     });
 };
 
+/** Event the desktop backend emits while it uploads or downloads an attachment file. */
+export const ATTACHMENT_PROGRESS_EVENT = 'attachment-transfer-progress';
+
+type NativeTransferProgress = {
+    id: string;
+    direction: 'upload' | 'download';
+    loaded: number;
+    total: number;
+};
+
+/** Forward the backend's progress events for `attachmentId` while `transfer` runs. */
+export const withNativeTransferProgress = async <T>(
+    attachmentId: string,
+    transfer: () => Promise<T>,
+): Promise<T> => {
+    let unlisten: (() => void) | undefined;
+    try {
+        const { listen } = await import('@tauri-apps/api/event');
+        unlisten = await listen<NativeTransferProgress>(ATTACHMENT_PROGRESS_EVENT, ({ payload }) => {
+            if (payload.id !== attachmentId) return;
+            reportProgress(payload.id, payload.direction, payload.loaded, payload.total, 'active');
+        });
+    } catch {
+        // Progress is cosmetic; transfer without it.
+    }
+    try {
+        return await transfer();
+    } finally {
+        unlisten?.();
+    }
+};
+
 export const collectAttachmentsById = (appData: AppData): Map<string, Attachment> => {
     const attachmentsById = new Map<string, Attachment>();
     for (const task of appData.tasks) {
@@ -1,9 +1,13 @@
 import {
     getFileSyncDir,
+    isEntitySyncFilePath,
     isSyncFilePath,
     normalizePath,
     normalizeSyncBackend,
+    type AppData,
     type Attachment,
+    type EntityMergeStats,
+    type MergeStats,
     type SyncBackend,
 } from '@mindwtr/core';
 
@@ -59,6 +63,7 @@ export const sleep = (ms: number) => new Promise<void>((resolve) => setTimeout(r
 
 export {
     getFileSyncDir,
+    isEntitySyncFilePath,
     isSyncFilePath,
     normalizePath,
     normalizeSyncBackend,
@@ -147,6 +152,91 @@ export const buildCloudKey = (attachment: Attachment): string => {
     return `${ATTACHMENTS_DIR_NAME}/${attachment.id}${ext}`;
 };
 
+/** Content-addressed key, so identical files share one remote copy. */
+export const buildContentCloudKey = (attachment: Attachment, fileHash: string): string => {
+    const ext = extractExtension(attachment.title) || extractExtension(attachment.uri);
+    return `${ATTACHMENTS_DIR_NAME}/${fileHash.toLowerCase()}${ext}`;
+};
+
+/** Whether `path` names a file directly inside `dir` (not in a subfolder). */
+export const isFileDirectlyInDir = (path: string, dir: string): boolean => {
+    const normalize = (value: string) => value.replace(/\\/g, '/').replace(/\/+$/, '');
+    const file = normalize(path);
+    const slash = file.lastIndexOf('/');
+    if (slash <= 0) return false;
+    const name = file.slice(slash + 1);
+    return name !== '' && name !== '.' && name !== '..' && file.slice(0, slash) === normalize(dir);
+};
+
 export const isTempAttachmentFile = (name: string): boolean => {
     return name.includes('.tmp-') || name.endsWith('.tmp') || name.endsWith('.partial');
 };
+
+export type BackendSyncConflict = {
+    entity: string;
+    id: string;
+    fields: string[];
+    resolution: 'local' | 'remote' | 'merged';
+    reason: string;
+};
+
+/** Conflict report of the desktop backend's three-way merge (`merge_sync_data`). */
+export type BackendMergeReport = {
+    hadBase: boolean;
+    localChanges: number;
+    remoteChanges: number;
+    fieldMerges: number;
+    conflicts: BackendSyncConflict[];
+    maxClockSkewMs: number;
+    clockSkewIds: string[];
+};
+
+export type BackendMergeOutcome = {
+    data: AppData;
+    report: BackendMergeReport;
+};
+
+const MERGE_STATS_COLLECTIONS = ['tasks', 'projects', 'sections', 'areas'] as const;
+
+/** Express a backend merge report as the per-collection stats the sync history stores. */
+export const buildMergeStatsFromReport = (
+    local: AppData,
+    remote: AppData | null,
+    merged: AppData,
+    report: BackendMergeReport
+): MergeStats => {
+    const buildEntityStats = (key: typeof MERGE_STATS_COLLECTIONS[number]): EntityMergeStats => {
+        const localItems = (local[key] || []) as Array<{ id: string }>;
+        const remoteItems = (remote?.[key] || []) as Array<{ id: string }>;
+        const mergedItems = (merged[key] || []) as Array<{ id: string; deletedAt?: string }>;
+        const localIds = new Set(localItems.map((item) => item.id));
+        const remoteIds = new Set(remoteItems.map((item) => item.id));
+        const mergedById = new Map(mergedItems.map((item) => [item.id, item]));
+        const conflicts = report.conflicts.filter((conflict) => conflict.entity === key);
+        const ids = new Set([...localIds, ...remoteIds]);
+        const skewIds = report.clockSkewIds.filter((id) => ids.has(id));
+        return {
+            localTotal: localItems.length,
+            incomingTotal: remoteItems.length,
+            mergedTotal: mergedItems.length,
+            localOnly: localItems.filter((item) => !remoteIds.has(item.id)).length,
+            incomingOnly: remoteItems.filter((item) => !localIds.has(item.id)).length,
+            conflicts: conflicts.length,
+            resolvedUsingLocal: conflicts.filter((conflict) => conflict.resolution === 'local').length,
+            resolvedUsingIncoming: conflicts.filter((conflict) => conflict.resolution === 'remote').length,
+            deletionsWon: conflicts.filter((conflict) => (
+                conflict.reason === 'delete-vs-edit' && Boolean(mergedById.get(conflict.id)?.deletedAt)
+            )).length,
+            conflictIds: conflicts.map((conflict) => conflict.id),
+            maxClockSkewMs: report.maxClockSkewMs,
+            timestampAdjustments: skewIds.length,
+            timestampAdjustmentIds: skewIds,
+        };
+    };
+    return {
+        tasks: buildEntityStats('tasks'),
+        projects: buildEntityStats('projects'),
+        sections: buildEntityStats('sections'),
+        areas: buildEntityStats('areas'),
+    };
+};
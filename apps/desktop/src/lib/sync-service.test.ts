@@ -1,6 +1,13 @@
 import { afterEach, describe, expect, it, vi } from 'vitest';
 import type { Attachment } from '@mindwtr/core';
-import { getFileSyncDir, hashString, normalizeSyncBackend } from './sync-service-utils';
+import {
+    buildCloudKey,
+    buildContentCloudKey,
+    getFileSyncDir,
+    hashString,
+    isFileDirectlyInDir,
+    normalizeSyncBackend,
+} from './sync-service-utils';
 import { SyncService, __syncServiceTestUtils } from './sync-service';
 
 afterEach(async () => {
@@ -15,6 +22,7 @@ describe('sync-service test utils', () => {
         expect(normalizeSyncBackend('file')).toBe('file');
         expect(normalizeSyncBackend('webdav')).toBe('webdav');
         expect(normalizeSyncBackend('cloud')).toBe('cloud');
+        expect(normalizeSyncBackend('s3')).toBe('s3');
         expect(normalizeSyncBackend('off')).toBe('off');
         expect(normalizeSyncBackend('unknown')).toBe('off');
         expect(normalizeSyncBackend(null)).toBe('off');
@@ -27,11 +35,57 @@ describe('sync-service test utils', () => {
         expect(getFileSyncDir('', 'data.json', 'mindwtr-sync.json')).toBe('');
     });
 
+    it('only treats files directly in the attachments folder as transferable in place', () => {
+        const dir = '/home/me/.local/share/mindwtr/attachments';
+        expect(isFileDirectlyInDir(`${dir}/att-1.pdf`, dir)).toBe(true);
+        expect(isFileDirectlyInDir(`${dir}/att-1.pdf`, `${dir}/`)).toBe(true);
+        expect(isFileDirectlyInDir('C:\\Users\\me\\mindwtr\\attachments\\a.png', 'C:\\Users\\me\\mindwtr\\attachments')).toBe(true);
+        expect(isFileDirectlyInDir(`${dir}/nested/att-1.pdf`, dir)).toBe(false);
+        expect(isFileDirectlyInDir(`${dir}/..`, dir)).toBe(false);
+        expect(isFileDirectlyInDir('/home/me/Documents/report.pdf', dir)).toBe(false);
+    });
+
     it('hashes sync payloads with sha256 output', async () => {
         const hash = await hashString('mindwtr');
         expect(hash).toBe('feb7a7b01b1c68e586e77288a4b2598d146ee3696ec7dbfac0074196b8d68c33');
     });
 
+    it('keys uploaded attachments by content hash so identical files share one copy', () => {
+        const base: Attachment = {
+            id: 'att-1',
+            kind: 'file',
+            title: 'Report.PDF',
+            uri: '/tmp/report.pdf',
+            createdAt: '2025-01-01T00:00:00.000Z',
+            updatedAt: '2025-01-01T00:00:00.000Z',
+        };
+        const hash = 'ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789';
+        expect(buildCloudKey(base)).toBe('attachments/att-1.pdf');
+        expect(buildContentCloudKey(base, hash)).toBe(`attachments/${hash.toLowerCase()}.pdf`);
+        expect(buildContentCloudKey({ ...base, id: 'att-2', title: 'Copy.pdf' }, hash))
+            .toBe(buildContentCloudKey(base, hash));
+    });
+
+    it('hashes uploaded bytes into the cloud key and records the hash on the attachment', async () => {
+        const bytes = new TextEncoder().encode('same bytes');
+        const hash = await hashString('same bytes');
+        const first: Attachment = {
+            id: 'att-1',
+            kind: 'file',
+            title: 'Scan.png',
+            uri: '/tmp/scan.png',
+            createdAt: '2025-01-01T00:00:00.000Z',
+            updatedAt: '2025-01-01T00:00:00.000Z',
+        };
+        const second: Attachment = { ...first, id: 'att-2', uri: '/tmp/scan-copy.png' };
+
+        expect(await __syncServiceTestUtils.resolveUploadCloudKey(first, bytes)).toBe(`attachments/${hash}.png`);
+        expect(first.fileHash).toBe(hash);
+        expect(await __syncServiceTestUtils.resolveUploadCloudKey(second, bytes)).toBe(`attachments/${hash}.png`);
+        expect(await __syncServiceTestUtils.resolveUploadCloudKey(second, new TextEncoder().encode('edited')))
+            .not.toBe(`attachments/${hash}.png`);
+    });
+
     it('marks attachments unrecoverable when validation failures hit retry cap', () => {
         const attachment: Attachment = {
             id: 'att-1',
@@ -113,6 +167,318 @@ describe('SyncService testability hooks', () => {
         expect(await SyncService.getCloudProvider()).toBe('selfhosted');
     });
 
+    it('saves S3 settings through the backend without reading the secret back', async () => {
+        const invoke = vi.fn(async (command: string) => {
+            if (command === 'get_s3_config') {
+                return {
+                    endpoint: 'http://localhost:9000',
+                    bucket: 'mindwtr',
+                    prefix: 'alice',
+                    region: '',
+                    accessKeyId: 'minio',
+                    hasSecretKey: true,
+                };
+            }
+            return true;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+
+        const config = await SyncService.getS3Config();
+        expect(config.hasSecretKey).toBe(true);
+        expect(config.secretKey).toBeUndefined();
+
+        await SyncService.setS3Config({ ...config, region: 'us-east-1' });
+        expect(invoke).toHaveBeenCalledWith('set_s3_config', {
+            endpoint: 'http://localhost:9000',
+            bucket: 'mindwtr',
+            prefix: 'alice',
+            region: 'us-east-1',
+            accessKeyId: 'minio',
+            secretKey: '',
+        });
+    });
+
+    it('keeps the stored SFTP key passphrase when saving without a new one', async () => {
+        const invoke = vi.fn(async (command: string) => {
+            if (command === 'get_sftp_config') {
+                return {
+                    host: 'home.example.com',
+                    port: '2222',
+                    username: 'alice',
+                    remotePath: 'mindwtr',
+                    keyPath: '~/.ssh/id_ed25519',
+                    hasKeyPassphrase: true,
+                };
+            }
+            return true;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+
+        const config = await SyncService.getSftpConfig();
+        expect(config.hasKeyPassphrase).toBe(true);
+        expect(config.keyPassphrase).toBeUndefined();
+
+        await SyncService.setSftpConfig(config);
+        expect(invoke).toHaveBeenCalledWith('set_sftp_config', {
+            host: 'home.example.com',
+            port: '2222',
+            username: 'alice',
+            remotePath: 'mindwtr',
+            keyPath: '~/.ssh/id_ed25519',
+            keyPassphrase: '',
+        });
+    });
+
+    it('sends the encryption commands to the configured sync target', async () => {
+        const status = { enabled: true, keyId: '0123456789abcdef', rotationPending: false };
+        const invoke = vi.fn(async (command: string) => {
+            if (command === 'get_sync_backend') return 'webdav';
+            if (command.endsWith('sync_encryption') || command === 'rotate_sync_encryption_key') return status;
+            if (command === 'get_sync_encryption_status') throw new Error('keyring locked');
+            return undefined;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+
+        expect(await SyncService.enableSyncEncryption('correct horse')).toEqual(status);
+        expect(invoke).toHaveBeenCalledWith('enable_sync_encryption', { passphrase: 'correct horse', dropboxClientId: undefined });
+        await SyncService.rotateSyncEncryptionKey('battery staple');
+        expect(invoke).toHaveBeenCalledWith('rotate_sync_encryption_key', { passphrase: 'battery staple', dropboxClientId: undefined });
+        await SyncService.disableSyncEncryption();
+        expect(invoke).toHaveBeenCalledWith('disable_sync_encryption', { dropboxClientId: undefined });
+        expect(await SyncService.getSyncEncryptionStatus()).toEqual({ enabled: false, keyId: null, rotationPending: false });
+    });
+
+    it('creates the secret vault only when asked to and reports its status', async () => {
+        const status = { exists: true, unlocked: true, keyringAvailable: false, storedSecrets: 2 };
+        const invoke = vi.fn(async (command: string) => {
+            if (command === 'get_secret_vault_status') throw new Error('backend unavailable');
+            return status;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+
+        expect(await SyncService.unlockSecretVault('correct horse', false)).toEqual(status);
+        expect(invoke).toHaveBeenCalledWith('unlock_secret_vault', { passphrase: 'correct horse', create: false });
+        await SyncService.unlockSecretVault('battery staple', true);
+        expect(invoke).toHaveBeenCalledWith('unlock_secret_vault', { passphrase: 'battery staple', create: true });
+        await SyncService.lockSecretVault();
+        expect(invoke).toHaveBeenCalledWith('lock_secret_vault', undefined);
+        expect(await SyncService.getSecretVaultStatus()).toBeNull();
+    });
+
+    it('rounds the background sync interval and reports local changes to the scheduler', async () => {
+        const invoke = vi.fn(async (command: string, args?: Record<string, unknown>) => {
+            if (command === 'set_sync_interval_minutes') return args?.minutes;
+            return undefined;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+
+        expect(await SyncService.setSyncInterval(14.6)).toBe(15);
+        expect(await SyncService.setSyncInterval(-5)).toBe(0);
+        SyncService.reportLocalChange();
+        expect(invoke).toHaveBeenCalledWith('report_local_change', undefined);
+    });
+
+    it('forwards sync folder events from the fs watcher to the scheduler', async () => {
+        const unwatch = vi.fn();
+        let onEvent: ((event: unknown) => void) | null = null;
+        const watch = vi.fn(async (_path: string, callback: (event: unknown) => void) => {
+            onEvent = callback;
+            return unwatch;
+        });
+        vi.doMock('@tauri-apps/plugin-fs', () => ({ watch }));
+        let syncPath = '/sync/a';
+        const invoke = vi.fn(async (command: string) => {
+            if (command === 'get_sync_backend') return 'file';
+            if (command === 'get_sync_path') return syncPath;
+            return undefined;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+
+        try {
+            await SyncService.startFileWatcher();
+            await SyncService.startFileWatcher();
+            expect(watch).toHaveBeenCalledTimes(1);
+            expect(watch).toHaveBeenCalledWith('/sync/a', expect.any(Function), { recursive: true });
+
+            onEvent!({ paths: ['/sync/a/data.json'] });
+            onEvent!({ paths: [] });
+            expect(invoke).toHaveBeenCalledWith('report_sync_folder_change', { paths: ['/sync/a/data.json'] });
+            expect(invoke.mock.calls.filter(([command]) => command === 'report_sync_folder_change')).toHaveLength(1);
+
+            syncPath = '/sync/b';
+            await SyncService.startFileWatcher();
+            expect(unwatch).toHaveBeenCalledTimes(1);
+            expect(watch).toHaveBeenLastCalledWith('/sync/b', expect.any(Function), { recursive: true });
+
+            await SyncService.stopFileWatcher();
+            expect(unwatch).toHaveBeenCalledTimes(2);
+        } finally {
+            vi.doUnmock('@tauri-apps/plugin-fs');
+        }
+    });
+
+    it('previews a file sync without writing either side', async () => {
+        const task = {
+            id: 'local-task',
+            title: 'Only here',
+            status: 'inbox',
+            tags: [],
+            contexts: [],
+            createdAt: '2024-01-01T00:00:00.000Z',
+            updatedAt: '2024-01-01T00:00:00.000Z',
+        };
+        const invoke = vi.fn(async (command: string, args?: Record<string, unknown>) => {
+            if (command === 'get_sync_backend') return 'file';
+            if (command === 'get_data') return { tasks: [task], projects: [], sections: [], areas: [], settings: {} };
+            if (command === 'read_sync_file') return { data: { tasks: [], projects: [], sections: [], areas: [], settings: {} }, version: 'v1' };
+            if (command === 'merge_sync_data') {
+                const report = { hadBase: false, localChanges: 1, remoteChanges: 0, fieldMerges: 0, conflicts: [], maxClockSkewMs: 0, clockSkewIds: [] };
+                return { data: args?.local, report };
+            }
+            return undefined;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+        (SyncService as any).didMigrate = true;
+
+        const preview = await SyncService.previewSync();
+
+        expect(preview.remote.created).toEqual([{ entity: 'task', id: 'local-task', title: 'Only here' }]);
+        expect(preview.local.created).toEqual([]);
+        expect(invoke).toHaveBeenCalledWith('read_sync_file', { dryRun: true });
+        expect(invoke).toHaveBeenCalledWith('merge_sync_data', expect.objectContaining({ backend: 'file', dryRun: true }));
+        const commands = invoke.mock.calls.map(([command]) => command);
+        expect(commands).not.toContain('save_data');
+        expect(commands).not.toContain('write_sync_file');
+    });
+
+    it('keeps the local data over an external sync folder change without merging it in', async () => {
+        const task = (id: string, title: string) => ({
+            id,
+            title,
+            status: 'inbox',
+            tags: [],
+            contexts: [],
+            createdAt: '2024-01-01T00:00:00.000Z',
+            updatedAt: '2024-01-01T00:00:00.000Z',
+        });
+        const invoke = vi.fn(async (command: string, _args?: Record<string, unknown>) => {
+            if (command === 'get_sync_backend') return 'file';
+            if (command === 'get_data') return { tasks: [task('local-task', 'Mine')], projects: [], sections: [], areas: [], settings: {} };
+            if (command === 'read_sync_file') {
+                return { data: { tasks: [task('external-task', 'Theirs')], projects: [], sections: [], areas: [], settings: {} }, version: 'external' };
+            }
+            if (command === 'write_sync_file') return { status: 'written' };
+            return undefined;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+        const performSync = vi.spyOn(SyncService, 'performSync').mockResolvedValue({ success: true });
+
+        const result = await SyncService.resolveExternalSyncChange('keep-local');
+
+        expect(result.success).toBe(true);
+        const write = invoke.mock.calls.find(([command]) => command === 'write_sync_file');
+        // An expected version makes the backend store the payload as-is rather than merge the folder into it.
+        expect(write?.[1]).toEqual(expect.objectContaining({ expectedVersion: 'external' }));
+        const written = (write?.[1] as { data: { tasks: Array<{ id: string }> } }).data;
+        expect(written.tasks.map((item) => item.id)).toEqual(['local-task']);
+        expect(performSync).toHaveBeenCalledTimes(1);
+        performSync.mockRestore();
+    });
+
+    it('downloads a file-sync attachment on first open and serves the cached copy afterwards', async () => {
+        const bytes = new TextEncoder().encode('attachment body');
+        const hash = await hashString('attachment body');
+        const cloudKey = `attachments/${hash}.pdf`;
+        const cached = new Set<string>();
+        const fs = {
+            BaseDirectory: { Data: 'data' },
+            exists: vi.fn(async (path: string, options?: { baseDir?: string }) => (
+                options?.baseDir ? cached.has(path) : path === `/sync/${cloudKey}`
+            )),
+            readFile: vi.fn(async () => bytes),
+            mkdir: vi.fn(async () => undefined),
+            writeFile: vi.fn(async () => undefined),
+            rename: vi.fn(async (_from: string, to: string) => {
+                cached.add(to);
+            }),
+            remove: vi.fn(async () => undefined),
+        };
+        const openBytes = vi.fn(async (_command: string, payload: Uint8Array) => payload);
+        vi.doMock('@tauri-apps/plugin-fs', () => fs);
+        vi.doMock('@tauri-apps/api/path', () => ({
+            dataDir: async () => '/data',
+            join: async (...parts: string[]) => parts.join('/'),
+        }));
+        vi.doMock('@tauri-apps/api/core', () => ({ invoke: openBytes }));
+        const invoke = vi.fn(async (command: string) => {
+            if (command === 'get_sync_backend') return 'file';
+            if (command === 'get_sync_path') return '/sync/data.json';
+            return undefined;
+        });
+        __syncServiceTestUtils.setDependenciesForTests({
+            isTauriRuntime: () => true,
+            invoke: invoke as unknown as <T>(command: string, args?: Record<string, unknown>) => Promise<T>,
+        });
+        (SyncService as any).didMigrate = true;
+        const attachment: Attachment = {
+            id: 'att-remote',
+            kind: 'file',
+            title: 'Report.pdf',
+            uri: '/other-device/report.pdf',
+            cloudKey,
+            fileHash: hash,
+            createdAt: '2025-01-01T00:00:00.000Z',
+            updatedAt: '2025-01-01T00:00:00.000Z',
+        };
+
+        try {
+            const cachedPath = `/data/mindwtr/attachments/${hash}.pdf`;
+            expect(await SyncService.ensureAttachmentAvailable(attachment)).toBe(cachedPath);
+            expect(fs.readFile).toHaveBeenCalledWith(`/sync/${cloudKey}`);
+            expect(openBytes).toHaveBeenCalledWith('open_sync_attachment_bytes', bytes);
+            expect(cached.has(`mindwtr/attachments/${hash}.pdf`)).toBe(true);
+
+            expect(await SyncService.ensureAttachmentAvailable(attachment)).toBe(cachedPath);
+            expect(fs.readFile).toHaveBeenCalledTimes(1);
+
+            // A copy whose content does not match its hash is not cached.
+            cached.clear();
+            fs.writeFile.mockClear();
+            const tampered = { ...attachment, fileHash: hash.replace(/^./, hash[0] === '0' ? '1' : '0') };
+            await expect(SyncService.ensureAttachmentAvailable(tampered)).rejects.toThrow('Integrity validation failed');
+            expect(fs.writeFile).not.toHaveBeenCalled();
+            expect(cached.size).toBe(0);
+        } finally {
+            vi.doUnmock('@tauri-apps/plugin-fs');
+            vi.doUnmock('@tauri-apps/api/path');
+            vi.doUnmock('@tauri-apps/api/core');
+        }
+    });
+
     it('treats Dropbox app key as build-time config', async () => {
         const baseline = await SyncService.getDropboxAppKey();
         await SyncService.setDropboxAppKey('abc123');
@@ -8,18 +8,12 @@ import {
     findOrphanedAttachments,
     removeOrphanedAttachmentsFromData,
     validateAttachmentForUpload,
+    computeSha256Hex,
+    DEFAULT_MAX_FILE_SIZE_BYTES,
     webdavGetJson,
     webdavPutJson,
-    webdavGetFile,
-    webdavPutFile,
-    webdavFileExists,
-    webdavMakeDirectory,
-    webdavDeleteFile,
-    cloudGetFile,
-    cloudPutFile,
-    cloudGetJson,
+    cloudGetJsonWithEtag,
     cloudPutJson,
-    cloudDeleteFile,
     flushPendingSave,
     performSyncCycle,
     mergeAppData,
@@ -45,7 +39,13 @@ import {
     shouldRunAttachmentCleanup,
     createAbortableFetch,
     normalizeCloudProvider,
+    summarizeSyncChanges,
+    previewSyncMerge,
+    mergeSettingsForSync,
+    SyncConflictError,
     type CloudProvider,
+    type MergeResult,
+    type SyncPreview,
 } from '@mindwtr/core';
 import { isTauriRuntime } from './runtime';
 import { reportError } from './report-error';
@@ -55,19 +55,22 @@ import { ExternalCalendarService } from './external-calendar-service';
 import { webStorage } from './storage-adapter-web';
 import {
     collectAttachmentsById,
-    getBaseSyncUrl,
-    getCloudBaseUrl,
     normalizePendingRemoteDeletes,
     reportProgress,
     syncBasicRemoteAttachments,
     validateAttachmentHash,
+    withNativeTransferProgress,
 } from './sync-attachments';
 import {
     ATTACHMENTS_DIR_NAME,
     buildCloudKey,
+    buildMergeStatsFromReport,
+    buildContentCloudKey,
     extractExtension,
     getFileSyncDir,
     hashString,
+    isEntitySyncFilePath,
+    isFileDirectlyInDir,
     isSyncFilePath,
     isTempAttachmentFile,
     normalizeSyncBackend,
@@ -84,18 +87,7 @@ import {
     handleAttachmentValidationFailure,
     markAttachmentUnrecoverable,
 } from './sync-attachment-validation';
-import type { SyncBackend } from './sync-service-utils';
-import {
-    deleteDropboxFile,
-    downloadDropboxAppData,
-    downloadDropboxFile,
-    DropboxConflictError,
-    DropboxFileNotFoundError,
-    DropboxUnauthorizedError,
-    testDropboxAccess,
-    uploadDropboxAppData,
-    uploadDropboxFile,
-} from './dropbox-sync';
+import type { BackendMergeOutcome, SyncBackend } from './sync-service-utils';
 
 export type ExternalSyncChangeResolution = 'keep-local' | 'use-external' | 'merge';
 export type { CloudProvider };
@@ -129,7 +121,6 @@ const WEBDAV_READ_RETRY_OPTIONS = {
 const CLOUD_ATTACHMENT_RETRY_OPTIONS = { maxAttempts: 5, baseDelayMs: 2000, maxDelayMs: 60_000 };
 const WEBDAV_ATTACHMENT_MIN_INTERVAL_MS = 400;
 const WEBDAV_ATTACHMENT_COOLDOWN_MS = 60_000;
-const WEBDAV_ATTACHMENT_MAX_DOWNLOADS_PER_SYNC = 10;
 const WEBDAV_ATTACHMENT_MAX_UPLOADS_PER_SYNC = 10;
 const WEBDAV_ATTACHMENT_MISSING_BACKOFF_MS = 15 * 60_000;
 const WEBDAV_ATTACHMENT_ERROR_BACKOFF_MS = 2 * 60_000;
@@ -182,6 +173,11 @@ const logSyncInfo = (message: string, extra?: Record<string, string>) => {
     void logInfo(message, { scope: 'sync', extra });
 };
 
+const measureSyncPayloadBytes = (data: unknown): number => {
+    if (data === null || data === undefined) return 0;
+    return new TextEncoder().encode(JSON.stringify(data)).length;
+};
+
 const getWebdavDownloadBackoff = (attachmentId: string): number | null => {
     return webdavDownloadBackoff.getBlockedUntil(attachmentId);
 };
@@ -231,14 +227,129 @@ const readLocalDataForSync = async (): Promise<AppData> => {
     });
 };
 
+/** A remote payload from a backend `*_get_json`, with the version the next put is conditional on. */
+type RemoteSyncRead = { data: AppData | null; version: string };
+/** `read_sync_file` result; the folder always reads as a payload, if only an empty one. */
+type SyncFolderRead = { data: AppData; version: string };
+
+// Same remote reads as a sync, minus anything with side effects: file sync skips quarantining damaged files
+// and git fetches the branch without moving its remote-tracking ref.
+const readRemoteDataForPreview = async (backend: SyncBackend): Promise<AppData | null> => {
+    if (backend === 'webdav') return (await tauriInvoke<RemoteSyncRead>('webdav_get_json')).data;
+    if (backend === 'cloud') {
+        if (await SyncService.getCloudProvider() === 'dropbox') {
+            const clientId = (await SyncService.getDropboxAppKey()).trim();
+            if (!clientId) {
+                throw new Error('Dropbox app key is not configured');
+            }
+            return (await tauriInvoke<RemoteSyncRead>('dropbox_get_json', { clientId })).data;
+        }
+        return (await tauriInvoke<RemoteSyncRead>('cloud_get_json')).data;
+    }
+    if (backend === 's3') return (await tauriInvoke<RemoteSyncRead>('s3_get_json')).data;
+    if (backend === 'sftp') return (await tauriInvoke<RemoteSyncRead>('sftp_get_json')).data;
+    if (backend === 'git') return (await tauriInvoke<RemoteSyncRead>('git_get_json', { dryRun: true })).data;
+    return (await tauriInvoke<SyncFolderRead>('read_sync_file', { dryRun: true })).data;
+};
+
+/** Result of a backend `*_put_json` / `write_sync_file` call; `data` is what ended up in the remote. */
+type SyncPutResult = { status: 'written' | 'conflict'; etag?: string | null; data?: AppData };
+
+/** Backend key of the merge base: Dropbox keeps its own next to the self-hosted cloud. */
+const resolveSyncBaseBackend = (backend: SyncBackend, cloudProvider: CloudProvider): string =>
+    backend === 'cloud' && cloudProvider === 'dropbox' ? 'dropbox' : backend;
+
+// Items go through the backend's three-way merge, which knows the last synced state; settings follow
+// the group rules shared with mobile.
+const mergeWithSyncBase = async (
+    baseBackend: string,
+    clientId: string | undefined,
+    local: AppData,
+    remote: AppData | null,
+    options: { dryRun?: boolean } = {}
+): Promise<MergeResult> => {
+    const outcome = await tauriInvoke<BackendMergeOutcome>('merge_sync_data', {
+        backend: baseBackend,
+        clientId,
+        local,
+        remote,
+        dryRun: options.dryRun ?? false,
+    });
+    const data: AppData = {
+        ...normalizeAppData(outcome.data),
+        settings: mergeSettingsForSync(local.settings, remote?.settings ?? {}),
+    };
+    return { data, stats: buildMergeStatsFromReport(local, remote, data, outcome.report) };
+};
+
 const LOCAL_ATTACHMENTS_DIR = `mindwtr/${ATTACHMENTS_DIR_NAME}`;
 const FILE_BACKEND_VALIDATION_CONFIG = {
-    maxFileSizeBytes: Number.POSITIVE_INFINITY,
+    maxFileSizeBytes: DEFAULT_MAX_FILE_SIZE_BYTES,
     blockedMimeTypes: [],
 };
 const CLEANUP_INTERVAL_MS = 24 * 60 * 60 * 1000;
 const UPLOAD_TIMEOUT_MS = 120_000;
 
+const getLocalAttachmentPath = (attachment: Attachment, cloudKey: string): string => {
+    const filename = cloudKey.split('/').pop() || `${attachment.id}${extractExtension(attachment.uri)}`;
+    return `${LOCAL_ATTACHMENTS_DIR}/${filename}`;
+};
+
+/**
+ * The backend only transfers files that sit directly in the app's attachments folder. A file
+ * attached from elsewhere is copied there for the upload and removed afterwards.
+ */
+const withUploadablePath = async <T>(
+    attachment: Attachment,
+    localPath: string,
+    fileData: Uint8Array,
+    upload: (path: string) => Promise<T>
+): Promise<T> => {
+    const { BaseDirectory, remove, writeFile } = await import('@tauri-apps/plugin-fs');
+    const { dataDir, join } = await import('@tauri-apps/api/path');
+    const attachmentsDir = await join(await dataDir(), LOCAL_ATTACHMENTS_DIR);
+    if (isFileDirectlyInDir(localPath, attachmentsDir)) {
+        return await upload(localPath);
+    }
+    const stagedName = `${attachment.id}.tmp-upload`;
+    const stagedRelative = `${LOCAL_ATTACHMENTS_DIR}/${stagedName}`;
+    await writeFile(stagedRelative, fileData, { baseDir: BaseDirectory.Data });
+    try {
+        return await upload(await join(attachmentsDir, stagedName));
+    } finally {
+        await remove(stagedRelative, { baseDir: BaseDirectory.Data }).catch(() => undefined);
+    }
+};
+
+// Uploads are keyed by content hash; fall back to the id-based key when hashing is unavailable.
+const resolveUploadCloudKey = async (attachment: Attachment, bytes: Uint8Array): Promise<string> => {
+    const fileHash = await computeSha256Hex(bytes);
+    if (!fileHash) return buildCloudKey(attachment);
+    attachment.fileHash = fileHash;
+    return buildContentCloudKey(attachment, fileHash);
+};
+
+// The backend encrypts attachment bytes when sync encryption is on, and passes them through otherwise.
+const transformSyncAttachmentBytes = async (
+    command: 'seal_sync_attachment_bytes' | 'open_sync_attachment_bytes',
+    bytes: Uint8Array
+): Promise<Uint8Array> => {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return new Uint8Array(await invoke<ArrayBuffer>(command, bytes));
+};
+
+// Attachments are downloaded on demand; sync only picks up copies already in the local cache.
+const adoptCachedAttachment = async (attachment: Attachment): Promise<boolean> => {
+    if (!attachment.cloudKey) return false;
+    const { BaseDirectory, exists } = await import('@tauri-apps/plugin-fs');
+    const { dataDir, join } = await import('@tauri-apps/api/path');
+    const relativePath = getLocalAttachmentPath(attachment, attachment.cloudKey);
+    if (!(await exists(relativePath, { baseDir: BaseDirectory.Data }))) return false;
+    attachment.uri = await join(await dataDir(), relativePath);
+    attachment.localStatus = 'available';
+    return true;
+};
+
 const cleanupAttachmentTempFiles = async (): Promise<void> => {
     if (!isTauriRuntimeEnv()) return;
     try {
@@ -290,15 +401,24 @@ const cleanupOrphanedAttachments = async (appData: AppData, backend: SyncBackend
     const cleanupTargets = new Map<string, Attachment>();
     for (const attachment of orphaned) cleanupTargets.set(attachment.id, attachment);
     for (const attachment of deletedAttachments) cleanupTargets.set(attachment.id, attachment);
+    // Content-hash keys and cached files can be shared, so keep anything a live attachment still uses.
+    const liveCloudKeys = new Set<string>();
+    const liveUris = new Set<string>();
+    for (const attachment of collectAttachmentsById(appData).values()) {
+        if (attachment.deletedAt || cleanupTargets.has(attachment.id)) continue;
+        if (attachment.cloudKey) liveCloudKeys.add(attachment.cloudKey);
+        if (attachment.uri) liveUris.add(attachment.uri);
+    }
     const remoteCleanupTargets = new Map<string, { cloudKey: string; title: string }>();
     for (const attachment of cleanupTargets.values()) {
-        if (!attachment.cloudKey) continue;
+        if (!attachment.cloudKey || liveCloudKeys.has(attachment.cloudKey)) continue;
         remoteCleanupTargets.set(attachment.cloudKey, {
             cloudKey: attachment.cloudKey,
             title: attachment.title || attachment.cloudKey,
         });
     }
     for (const pending of previousPendingRemoteDeletes) {
+        if (liveCloudKeys.has(pending.cloudKey)) continue;
         remoteCleanupTargets.set(pending.cloudKey, {
             cloudKey: pending.cloudKey,
             title: pending.title || pending.cloudKey,
@@ -325,7 +445,6 @@ const cleanupOrphanedAttachments = async (appData: AppData, backend: SyncBackend
     let cloudConfig: CloudConfig | null = null;
     let cloudProvider: CloudProvider = 'selfhosted';
     let dropboxAppKey = '';
-    let dropboxAccessToken: string | null = null;
     let fileBaseDir: string | null = null;
 
     if (backend === 'webdav') {
@@ -343,36 +462,10 @@ const cleanupOrphanedAttachments = async (appData: AppData, backend: SyncBackend
         fileBaseDir = baseDir || null;
     }
 
-    const fetcher = await getTauriFetch();
-    const dropboxFetcher = fetcher ?? fetch;
-    const webdavPassword = webdavConfig ? await resolveWebdavPassword(webdavConfig) : '';
     const nextPendingRemoteDeletes = new Map<string, PendingRemoteAttachmentDeleteEntry>();
-    const resolveDropboxAccessToken = async (forceRefresh = false): Promise<string> => {
-        if (!dropboxAppKey) {
-            throw new Error('Dropbox app key is not configured');
-        }
-        if (!dropboxAccessToken || forceRefresh) {
-            dropboxAccessToken = await SyncService.getDropboxAccessToken(dropboxAppKey, { forceRefresh });
-        }
-        return dropboxAccessToken;
-    };
-    const deleteDropboxAttachment = async (cloudKey: string): Promise<void> => {
-        const run = async (forceRefresh: boolean) => {
-            const token = await resolveDropboxAccessToken(forceRefresh);
-            await deleteDropboxFile(token, cloudKey, dropboxFetcher);
-        };
-        try {
-            await run(false);
-        } catch (error) {
-            if (error instanceof DropboxUnauthorizedError) {
-                await run(true);
-                return;
-            }
-            throw error;
-        }
-    };
 
     for (const attachment of cleanupTargets.values()) {
+        if (attachment.uri && liveUris.has(attachment.uri)) continue;
         await deleteAttachmentFile(attachment);
     }
 
@@ -380,6 +473,8 @@ const cleanupOrphanedAttachments = async (appData: AppData, backend: SyncBackend
         (backend === 'webdav' && !!webdavConfig?.url)
         || (backend === 'cloud' && cloudProvider === 'selfhosted' && !!cloudConfig?.url)
         || (backend === 'cloud' && cloudProvider === 'dropbox' && !!dropboxAppKey)
+        || backend === 's3'
+        || backend === 'sftp'
         || (backend === 'file' && !!fileBaseDir)
     );
     for (const target of remoteCleanupTargets.values()) {
@@ -395,20 +490,18 @@ const cleanupOrphanedAttachments = async (appData: AppData, backend: SyncBackend
         }
         try {
             if (backend === 'webdav' && webdavConfig?.url) {
-                const baseUrl = getBaseSyncUrl(webdavConfig.url);
-                await webdavDeleteFile(`${baseUrl}/${target.cloudKey}`, {
-                    username: webdavConfig.username,
-                    password: webdavPassword,
-                    fetcher,
-                });
+                await tauriInvoke<boolean>('webdav_delete_file', { remotePath: target.cloudKey });
             } else if (backend === 'cloud' && cloudProvider === 'selfhosted' && cloudConfig?.url) {
-                const baseUrl = getCloudBaseUrl(cloudConfig.url);
-                await cloudDeleteFile(`${baseUrl}/${target.cloudKey}`, {
-                    token: cloudConfig.token,
-                    fetcher,
-                });
+                await tauriInvoke<boolean>('cloud_delete_file', { remotePath: target.cloudKey });
             } else if (backend === 'cloud' && cloudProvider === 'dropbox') {
-                await deleteDropboxAttachment(target.cloudKey);
+                await tauriInvoke<boolean>('dropbox_delete_file', {
+                    clientId: dropboxAppKey,
+                    remotePath: target.cloudKey,
+                });
+            } else if (backend === 's3') {
+                await tauriInvoke<boolean>('s3_delete_file', { remotePath: target.cloudKey });
+            } else if (backend === 'sftp') {
+                await tauriInvoke<boolean>('sftp_delete_file', { remotePath: target.cloudKey });
             } else if (backend === 'file' && fileBaseDir) {
                 const { remove } = await import('@tauri-apps/plugin-fs');
                 const { join } = await import('@tauri-apps/api/path');
@@ -417,7 +510,7 @@ const cleanupOrphanedAttachments = async (appData: AppData, backend: SyncBackend
             }
         } catch (error) {
             const status = getErrorStatus(error);
-            if (status === 404 || error instanceof DropboxFileNotFoundError) {
+            if (status === 404) {
                 logSyncInfo('Remote attachment already missing during cleanup', {
                     cloudKey: target.cloudKey,
                 });
@@ -455,9 +548,109 @@ async function tauriInvoke<T>(command: string, args?: Record<string, unknown>):
 }
 
 type WebDavConfig = { url: string; username: string; password?: string; hasPassword?: boolean };
-type CloudConfig = { url: string; token: string };
+type CloudConfig = { url: string; token?: string; hasToken?: boolean };
+export type S3Config = {
+    endpoint: string;
+    bucket: string;
+    prefix: string;
+    region: string;
+    accessKeyId: string;
+    /** Only sent when changing it; the stored key is never read back. */
+    secretKey?: string;
+    hasSecretKey?: boolean;
+};
+
+const buildS3SyncUrl = (config: S3Config | null): string => {
+    const prefix = config?.prefix ? `${config.prefix}/` : '';
+    return `s3://${config?.bucket ?? ''}/${prefix}${SYNC_FILE_NAME}`;
+};
+export type GitConfig = {
+    repoPath: string;
+    /** Empty means `origin`. */
+    remote: string;
+    /** Empty means the branch currently checked out. */
+    branch: string;
+};
+export type SftpConfig = {
+    host: string;
+    /** Empty means 22. */
+    port: string;
+    username: string;
+    /** Empty means `mindwtr` in the login directory. */
+    remotePath: string;
+    /** Empty means the keys loaded in ssh-agent. */
+    keyPath: string;
+    /** Only sent when changing it; the stored passphrase is never read back. */
+    keyPassphrase?: string;
+    hasKeyPassphrase?: boolean;
+};
+export type SyncEncryptionStatus = {
+    enabled: boolean;
+    keyId: string | null;
+    /** A key rotation stopped part-way; running it again with the same passphrase finishes it. */
+    rotationPending: boolean;
+};
+/** The passphrase-protected credential store used when the OS keyring is unavailable. */
+export type SecretVaultStatus = {
+    exists: boolean;
+    unlocked: boolean;
+    keyringAvailable: boolean;
+    storedSecrets: number;
+    /** The user chose unencrypted secrets.toml over creating a vault. */
+    plaintextAllowed: boolean;
+};
+/** `single` keeps everything in data.json; `entities` writes one file per item plus manifest.json. */
+export type SyncFileLayout = 'single' | 'entities';
+/** Payload of the `sync-conflict-copies` event: conflict copies merged from the sync folder and archived. */
+export type SyncConflictCopiesReport = {
+    files: string[];
+    mergedItems: number;
+    conflicts: Array<{ entity: string; id: string; fields: string[]; resolution: string; reason: string }>;
+    archiveDir: string;
+};
+/** Payload of the `sync-file-quarantined` event: a sync file failed validation and a copy of it was kept in quarantine. */
+export type SyncQuarantineReport = {
+    file: string;
+    reason: string;
+    quarantinePath: string;
+    recoveredFromBackup: boolean;
+};
+/** One recorded sync run from the desktop sync journal. */
+export type SyncJournalEntry = {
+    id?: number;
+    startedAt: string;
+    finishedAt?: string | null;
+    backend: SyncBackend;
+    direction: 'push' | 'pull' | 'merge';
+    bytes: number;
+    added: number;
+    updated: number;
+    deleted: number;
+    conflicts: number;
+    error?: string | null;
+};
+export type SyncJournal = {
+    entries: SyncJournalEntry[];
+    lastSuccessAt: string | null;
+};
+/** Payload of the `auto-sync-requested` event emitted by the backend scheduler. */
+export type AutoSyncRequest = {
+    reason: 'interval' | 'local-change' | 'sync-folder' | 'retry';
+    paths: string[];
+    attempt: number;
+};
+/** Payload of the `config-changed` event emitted when the config files change outside the app. */
+export type ConfigChangedEvent = {
+    keys: string[];
+    /** Validation errors; when present the backend kept the previous config. */
+    errors: string[];
+};
+export type DropboxRevision = { rev: string; serverModified?: string | null; size?: number | null };
 const DROPBOX_REDIRECT_URI_FALLBACK = 'http://127.0.0.1:53682/oauth/dropbox/callback';
 const DROPBOX_TEST_TIMEOUT_MS = 15_000;
+const S3_TEST_TIMEOUT_MS = 15_000;
+const GIT_TEST_TIMEOUT_MS = 30_000;
+const SFTP_TEST_TIMEOUT_MS = 20_000;
 
 const withTimeout = async <T>(promise: Promise<T>, ms: number, message: string): Promise<T> => {
     let timer: ReturnType<typeof setTimeout> | undefined;
@@ -475,41 +668,15 @@ async function getTauriFetch(): Promise<typeof fetch | undefined> {
     return syncServiceDependencies.getTauriFetch();
 }
 
-async function resolveWebdavPassword(config: WebDavConfig): Promise<string> {
-    if (typeof config.password === 'string') return config.password;
-    if (config.hasPassword === false) return '';
-    if (!isTauriRuntimeEnv()) return '';
-    try {
-        return await tauriInvoke<string>('get_webdav_password');
-    } catch (error) {
-        logSyncWarning('Failed to load WebDAV password', error);
-        return '';
-    }
-}
-
 async function syncAttachments(
     appData: AppData,
-    webDavConfig: WebDavConfig,
-    baseSyncUrl: string
+    webDavConfig: WebDavConfig
 ): Promise<AppData | null> {
     if (!isTauriRuntimeEnv()) return null;
     if (!webDavConfig.url) return null;
 
-    const fetcher = await getTauriFetch();
-    const { BaseDirectory, exists, mkdir, readFile, writeFile, rename, remove } = await import('@tauri-apps/plugin-fs');
-    const { dataDir, join } = await import('@tauri-apps/api/path');
-    const password = await resolveWebdavPassword(webDavConfig);
-
-    const attachmentsDirUrl = `${baseSyncUrl}/${ATTACHMENTS_DIR_NAME}`;
-    try {
-        await webdavMakeDirectory(attachmentsDirUrl, {
-            username: webDavConfig.username,
-            password,
-            fetcher,
-        });
-    } catch (error) {
-        logSyncWarning('Failed to ensure WebDAV attachments directory', error);
-    }
+    const { BaseDirectory, exists, mkdir, readFile } = await import('@tauri-apps/plugin-fs');
+    const { dataDir } = await import('@tauri-apps/api/path');
 
     try {
         await mkdir(LOCAL_ATTACHMENTS_DIR, { baseDir: BaseDirectory.Data, recursive: true });
@@ -546,6 +713,25 @@ async function syncAttachments(
         return true;
     };
 
+    // One listing answers every "is it uploaded?" question for this run.
+    let remoteKeys: Set<string>;
+    try {
+        const entries = await withRetry(
+            async () => {
+                await waitForSlot();
+                return await tauriInvoke<Array<{ path: string; isCollection: boolean }>>('webdav_list_files', {
+                    path: ATTACHMENTS_DIR_NAME,
+                });
+            },
+            WEBDAV_ATTACHMENT_RETRY_OPTIONS
+        );
+        remoteKeys = new Set(entries.filter((entry) => !entry.isCollection).map((entry) => entry.path));
+    } catch (error) {
+        handleRateLimit(error);
+        logSyncWarning('Failed to list WebDAV attachments', error);
+        return null;
+    }
+
     const readLocalFile = async (path: string): Promise<Uint8Array> => {
         if (path.startsWith(baseDataDir)) {
             const relative = path.slice(baseDataDir.length).replace(/^[\\/]/, '');
@@ -568,7 +754,6 @@ async function syncAttachments(
     };
 
     let didMutate = false;
-    const downloadQueue: Attachment[] = [];
     let abortedByRateLimit = false;
     let uploadCount = 0;
     let uploadLimitLogged = false;
@@ -601,34 +786,10 @@ async function syncAttachments(
             webdavDownloadBackoff.deleteEntry(attachment.id);
         }
 
-        if (attachment.cloudKey && existsLocally) {
-            try {
-                const remoteExists = await withRetry(
-                    async () => {
-                        await waitForSlot();
-                        return await webdavFileExists(`${baseSyncUrl}/${attachment.cloudKey}`, {
-                            username: webDavConfig.username,
-                            password,
-                            fetcher,
-                        });
-                    },
-                    WEBDAV_ATTACHMENT_RETRY_OPTIONS
-                );
-                logSyncInfo('WebDAV attachment remote exists', {
-                    id: attachment.id,
-                    exists: remoteExists ? 'true' : 'false',
-                });
-                if (!remoteExists) {
-                    attachment.cloudKey = undefined;
-                    didMutate = true;
-                }
-            } catch (error) {
-                if (handleRateLimit(error)) {
-                    abortedByRateLimit = true;
-                    break;
-                }
-                logSyncWarning('Failed to check WebDAV attachment remote status', error);
-            }
+        if (attachment.cloudKey && existsLocally && !remoteKeys.has(attachment.cloudKey)) {
+            logSyncInfo('WebDAV attachment missing remotely', { id: attachment.id });
+            attachment.cloudKey = undefined;
+            didMutate = true;
         }
 
         if (!attachment.cloudKey && existsLocally) {
@@ -642,7 +803,6 @@ async function syncAttachments(
                 continue;
             }
             uploadCount += 1;
-            const cloudKey = buildCloudKey(attachment);
             try {
                 const fileData = await readLocalFile(localPath);
                 const validation = await validateAttachmentForUpload(attachment, fileData.length);
@@ -665,40 +825,41 @@ async function syncAttachments(
                     continue;
                 }
                 clearAttachmentValidationFailure(attachment.id);
+                const cloudKey = await resolveUploadCloudKey(attachment, fileData);
                 reportProgress(attachment.id, 'upload', 0, fileData.length, 'active');
+                const alreadyUploaded = remoteKeys.has(cloudKey);
                 logSyncInfo('WebDAV attachment upload start', {
                     id: attachment.id,
                     bytes: String(fileData.length),
                     cloudKey,
+                    dedup: alreadyUploaded ? 'true' : 'false',
                 });
-                await withRetry(
-                    async () => {
-                        await waitForSlot();
-                        return await webdavPutFile(
-                            `${baseSyncUrl}/${cloudKey}`,
-                            fileData,
-                            attachment.mimeType || 'application/octet-stream',
-                            {
-                                headers: { 'Content-Length': String(fileData.length) },
-                                username: webDavConfig.username,
-                                password,
-                                fetcher,
-                                timeoutMs: UPLOAD_TIMEOUT_MS,
-                            }
-                        );
-                    },
-                    {
-                        ...WEBDAV_ATTACHMENT_RETRY_OPTIONS,
-                        onRetry: (error, attempt, delayMs) => {
-                            logSyncInfo('Retrying WebDAV attachment upload', {
-                                id: attachment.id,
-                                attempt: String(attempt + 1),
-                                delayMs: String(delayMs),
-                                error: sanitizeLogMessage(error instanceof Error ? error.message : String(error)),
-                            });
+                if (!alreadyUploaded) {
+                    // The backend seals the file when sync encryption is on.
+                    await withUploadablePath(attachment, localPath, fileData, (uploadPath) => withRetry(
+                        async () => {
+                            await waitForSlot();
+                            return await withNativeTransferProgress(attachment.id, () => tauriInvoke('webdav_upload_file', {
+                                remotePath: cloudKey,
+                                localPath: uploadPath,
+                                contentType: attachment.mimeType || 'application/octet-stream',
+                                progressId: attachment.id,
+                            }));
                         },
-                    }
-                );
+                        {
+                            ...WEBDAV_ATTACHMENT_RETRY_OPTIONS,
+                            onRetry: (error, attempt, delayMs) => {
+                                logSyncInfo('Retrying WebDAV attachment upload', {
+                                    id: attachment.id,
+                                    attempt: String(attempt + 1),
+                                    delayMs: String(delayMs),
+                                    error: sanitizeLogMessage(error instanceof Error ? error.message : String(error)),
+                                });
+                            },
+                        }
+                    ));
+                    remoteKeys.add(cloudKey);
+                }
                 attachment.cloudKey = cloudKey;
                 attachment.localStatus = 'available';
                 didMutate = true;
@@ -726,88 +887,13 @@ async function syncAttachments(
         }
 
         if (attachment.cloudKey && !existsLocally) {
-            downloadQueue.push(attachment);
-        }
-    }
-
-    let downloadCount = 0;
-    for (const attachment of downloadQueue) {
-        if (attachment.kind !== 'file') continue;
-        if (attachment.deletedAt) continue;
-        if (abortedByRateLimit) break;
-        if (!attachment.cloudKey) continue;
-        if (getWebdavDownloadBackoff(attachment.id)) continue;
-        if (downloadCount >= WEBDAV_ATTACHMENT_MAX_DOWNLOADS_PER_SYNC) {
-            logSyncInfo('WebDAV attachment download limit reached', {
-                limit: String(WEBDAV_ATTACHMENT_MAX_DOWNLOADS_PER_SYNC),
-            });
-            break;
-        }
-        downloadCount += 1;
-
-        const cloudKey = attachment.cloudKey;
-        try {
-            const downloadUrl = `${baseSyncUrl}/${cloudKey}`;
-            const fileData = await withRetry(
-                async () => {
-                    await waitForSlot();
-                    return await webdavGetFile(downloadUrl, {
-                        username: webDavConfig.username,
-                        password,
-                        fetcher,
-                        onProgress: (loaded, total) => reportProgress(attachment.id, 'download', loaded, total, 'active'),
-                    });
-                },
-                WEBDAV_ATTACHMENT_RETRY_OPTIONS
-            );
-            const bytes = fileData instanceof ArrayBuffer ? new Uint8Array(fileData) : new Uint8Array(fileData as ArrayBuffer);
-            await validateAttachmentHash(attachment, bytes);
-            const filename = cloudKey.split('/').pop() || `${attachment.id}${extractExtension(attachment.uri)}`;
-            const relativePath = `${LOCAL_ATTACHMENTS_DIR}/${filename}`;
-            await writeAttachmentFileSafely(relativePath, bytes, {
-                baseDir: BaseDirectory.Data,
-                writeFile,
-                rename,
-                remove,
-            });
-            const absolutePath = await join(baseDataDir, relativePath);
-            attachment.uri = absolutePath;
-            if (attachment.localStatus !== 'available') {
-                attachment.localStatus = 'available';
-                didMutate = true;
-            }
-            webdavDownloadBackoff.deleteEntry(attachment.id);
-            reportProgress(attachment.id, 'download', bytes.length, bytes.length, 'completed');
-        } catch (error) {
-            if (handleRateLimit(error)) {
-                abortedByRateLimit = true;
-                break;
-            }
-            const status = getErrorStatus(error);
-            if (status === 404 && attachment.cloudKey) {
-                webdavDownloadBackoff.deleteEntry(attachment.id);
-                if (markAttachmentUnrecoverable(attachment)) {
+            try {
+                if (await adoptCachedAttachment(attachment)) {
                     didMutate = true;
                 }
-                logSyncInfo('Cleared missing WebDAV cloud key after 404', {
-                    id: attachment.id,
-                });
-            } else {
-                setWebdavDownloadBackoff(attachment.id, error);
-            }
-            if (status !== 404 && attachment.localStatus !== 'missing') {
-                attachment.localStatus = 'missing';
-                didMutate = true;
+            } catch (error) {
+                logSyncWarning('Failed to check cached attachment', error);
             }
-            reportProgress(
-                attachment.id,
-                'download',
-                0,
-                attachment.size ?? 0,
-                'failed',
-                error instanceof Error ? error.message : String(error)
-            );
-            logSyncWarning(`Failed to download attachment ${attachment.title}`, error);
         }
     }
 
@@ -818,16 +904,25 @@ async function syncAttachments(
     return didMutate ? workingData : null;
 }
 
-async function syncCloudAttachments(
+/**
+ * Attachment transfer for backends whose credentials live in the desktop backend,
+ * which performs the actual upload and download.
+ */
+type NativeAttachmentTransport = {
+    label: string;
+    /** `localPath` is always directly inside the app's attachments folder. */
+    upload: (remotePath: string, localPath: string, contentType: string, progressId: string) => Promise<unknown>;
+    /** Resolves false when the remote file does not exist. */
+    download: (remotePath: string, localPath: string, progressId: string) => Promise<boolean>;
+    /** Whether a missing remote file marks the attachment unrecoverable instead of failing. */
+    missingIsUnrecoverable: boolean;
+};
+
+async function syncNativeAttachments(
     appData: AppData,
-    cloudConfig: CloudConfig,
-    baseSyncUrl: string
+    transport: NativeAttachmentTransport
 ): Promise<boolean> {
-    if (!isTauriRuntimeEnv()) return false;
-    if (!cloudConfig.url) return false;
-
-    const fetcher = await getTauriFetch();
-    const { BaseDirectory, exists, mkdir, readFile, writeFile, rename, remove } = await import('@tauri-apps/plugin-fs');
+    const { BaseDirectory, exists, mkdir, readFile, remove } = await import('@tauri-apps/plugin-fs');
     const { dataDir, join } = await import('@tauri-apps/api/path');
 
     try {
@@ -837,7 +932,6 @@ async function syncCloudAttachments(
     }
 
     const baseDataDir = await dataDir();
-
     const attachmentsById = collectAttachmentsById(appData);
 
     const readLocalFile = async (path: string): Promise<Uint8Array> => {
@@ -887,22 +981,16 @@ async function syncCloudAttachments(
             }
             clearAttachmentValidationFailure(attachment.id);
             reportProgress(attachment.id, 'upload', 0, fileData.length, 'active');
-            await withRetry(
-                () => cloudPutFile(
-                    `${baseSyncUrl}/${cloudKey}`,
-                    fileData,
-                    attachment.mimeType || 'application/octet-stream',
-                    {
-                        token: cloudConfig.token,
-                        fetcher,
-                        timeoutMs: UPLOAD_TIMEOUT_MS,
-                        onProgress: (loaded, total) => reportProgress(attachment.id, 'upload', loaded, total, 'active'),
-                    }
+            const contentType = attachment.mimeType || 'application/octet-stream';
+            await withUploadablePath(attachment, localPath, fileData, (uploadPath) => withRetry(
+                () => withNativeTransferProgress(
+                    attachment.id,
+                    () => transport.upload(cloudKey, uploadPath, contentType, attachment.id)
                 ),
                 {
                     ...CLOUD_ATTACHMENT_RETRY_OPTIONS,
                     onRetry: (error, attempt, delayMs) => {
-                        logSyncInfo('Retrying cloud attachment upload', {
+                        logSyncInfo(`Retrying ${transport.label} attachment upload`, {
                             id: attachment.id,
                             attempt: String(attempt + 1),
                             delayMs: String(delayMs),
@@ -910,7 +998,7 @@ async function syncCloudAttachments(
                         });
                     },
                 }
-            );
+            ));
             attachment.cloudKey = cloudKey;
             attachment.localStatus = 'available';
             reportProgress(attachment.id, 'upload', fileData.length, fileData.length, 'completed');
@@ -929,25 +1017,28 @@ async function syncCloudAttachments(
         },
         onDownload: async (attachment) => {
             if (!attachment.cloudKey) return false;
-            const downloadUrl = `${baseSyncUrl}/${attachment.cloudKey}`;
-            const fileData = await withRetry(() =>
-                cloudGetFile(downloadUrl, {
-                    token: cloudConfig.token,
-                    fetcher,
-                    onProgress: (loaded, total) => reportProgress(attachment.id, 'download', loaded, total, 'active'),
-                })
-            );
-            const bytes = fileData instanceof ArrayBuffer ? new Uint8Array(fileData) : new Uint8Array(fileData as ArrayBuffer);
-            await validateAttachmentHash(attachment, bytes);
-            const filename = attachment.cloudKey.split('/').pop() || `${attachment.id}${extractExtension(attachment.uri)}`;
+            const cloudKey = attachment.cloudKey;
+            const filename = cloudKey.split('/').pop() || `${attachment.id}${extractExtension(attachment.uri)}`;
             const relativePath = `${LOCAL_ATTACHMENTS_DIR}/${filename}`;
-            await writeAttachmentFileSafely(relativePath, bytes, {
-                baseDir: BaseDirectory.Data,
-                writeFile,
-                rename,
-                remove,
-            });
             const absolutePath = await join(baseDataDir, relativePath);
+            reportProgress(attachment.id, 'download', 0, attachment.size ?? 0, 'active');
+            const found = await withRetry(() => withNativeTransferProgress(
+                attachment.id,
+                () => transport.download(cloudKey, absolutePath, attachment.id)
+            ));
+            if (!found) {
+                if (transport.missingIsUnrecoverable) {
+                    return markAttachmentUnrecoverable(attachment);
+                }
+                throw new Error(`Attachment ${cloudKey} was not found on the server`);
+            }
+            const bytes = await readFile(relativePath, { baseDir: BaseDirectory.Data });
+            try {
+                await validateAttachmentHash(attachment, bytes);
+            } catch (error) {
+                await remove(relativePath, { baseDir: BaseDirectory.Data }).catch(() => undefined);
+                throw error;
+            }
             attachment.uri = absolutePath;
             const statusChanged = attachment.localStatus !== 'available';
             if (statusChanged) {
@@ -970,168 +1061,62 @@ async function syncCloudAttachments(
     });
 }
 
-async function syncDropboxAttachments(
+async function syncCloudAttachments(
     appData: AppData,
-    resolveAccessToken: (forceRefresh?: boolean) => Promise<string>
+    cloudConfig: CloudConfig
 ): Promise<boolean> {
     if (!isTauriRuntimeEnv()) return false;
+    if (!cloudConfig.url) return false;
+    // The backend holds the cloud token and performs the transfer.
+    return await syncNativeAttachments(appData, {
+        label: 'cloud',
+        upload: (remotePath, localPath, contentType, progressId) =>
+            tauriInvoke<boolean>('cloud_upload_file', { remotePath, localPath, contentType, progressId }),
+        download: (remotePath, localPath, progressId) =>
+            tauriInvoke<boolean>('cloud_download_file', { remotePath, localPath, progressId }),
+        missingIsUnrecoverable: false,
+    });
+}
 
-    const fetcher = await getTauriFetch();
-    const dropboxFetcher = fetcher ?? fetch;
-    const { BaseDirectory, exists, mkdir, readFile, writeFile, rename, remove } = await import('@tauri-apps/plugin-fs');
-    const { dataDir, join } = await import('@tauri-apps/api/path');
-
-    try {
-        await mkdir(LOCAL_ATTACHMENTS_DIR, { baseDir: BaseDirectory.Data, recursive: true });
-    } catch (error) {
-        logSyncWarning('Failed to ensure local attachments directory', error);
-    }
-
-    const baseDataDir = await dataDir();
-    const attachmentsById = collectAttachmentsById(appData);
-
-    const withDropboxAccess = async <T>(operation: (accessToken: string) => Promise<T>): Promise<T> => {
-        try {
-            const token = await resolveAccessToken(false);
-            return await operation(token);
-        } catch (error) {
-            if (error instanceof DropboxUnauthorizedError) {
-                const refreshed = await resolveAccessToken(true);
-                return await operation(refreshed);
-            }
-            throw error;
-        }
-    };
-
-    const readLocalFile = async (path: string): Promise<Uint8Array> => {
-        if (path.startsWith(baseDataDir)) {
-            const relative = path.slice(baseDataDir.length).replace(/^[\\/]/, '');
-            return await readFile(relative, { baseDir: BaseDirectory.Data });
-        }
-        return await readFile(path);
-    };
+async function syncDropboxAttachments(
+    appData: AppData,
+    dropboxAppKey: string
+): Promise<boolean> {
+    if (!isTauriRuntimeEnv()) return false;
+    // The backend holds the Dropbox tokens and performs the transfer.
+    return await syncNativeAttachments(appData, {
+        label: 'Dropbox',
+        upload: (remotePath, localPath, _contentType, progressId) =>
+            tauriInvoke<boolean>('dropbox_upload_file', { clientId: dropboxAppKey, remotePath, localPath, progressId }),
+        download: (remotePath, localPath, progressId) =>
+            tauriInvoke<boolean>('dropbox_download_file', { clientId: dropboxAppKey, remotePath, localPath, progressId }),
+        missingIsUnrecoverable: true,
+    });
+}
 
-    const localFileExists = async (path: string): Promise<boolean> => {
-        try {
-            if (path.startsWith(baseDataDir)) {
-                const relative = path.slice(baseDataDir.length).replace(/^[\\/]/, '');
-                return await exists(relative, { baseDir: BaseDirectory.Data });
-            }
-            return await exists(path);
-        } catch (error) {
-            logSyncWarning('Failed to check attachment file', error);
-            return false;
-        }
-    };
+async function syncS3Attachments(appData: AppData): Promise<boolean> {
+    if (!isTauriRuntimeEnv()) return false;
+    // The backend holds the S3 secret key, signs the requests and encrypts when enabled.
+    return await syncNativeAttachments(appData, {
+        label: 'S3',
+        upload: (remotePath, localPath, contentType) =>
+            tauriInvoke<string | null>('s3_upload_file', { remotePath, localPath, contentType }),
+        download: (remotePath, localPath) =>
+            tauriInvoke<boolean>('s3_download_file', { remotePath, localPath }),
+        missingIsUnrecoverable: true,
+    });
+}
 
-    return await syncBasicRemoteAttachments({
-        attachmentsById,
-        localFileExists,
-        onUpload: async (attachment, localPath) => {
-            const cloudKey = buildCloudKey(attachment);
-            const fileData = await readLocalFile(localPath);
-            const validation = await validateAttachmentForUpload(attachment, fileData.length);
-            if (!validation.valid) {
-                const failure = handleAttachmentValidationFailure(attachment, validation.error);
-                reportProgress(
-                    attachment.id,
-                    'upload',
-                    0,
-                    attachment.size ?? fileData.length,
-                    'failed',
-                    failure.message
-                );
-                if (failure.reachedLimit) {
-                    logSyncWarning(`${failure.message}; marking attachment unrecoverable`);
-                } else {
-                    logSyncWarning(failure.message);
-                }
-                return failure.mutated;
-            }
-            clearAttachmentValidationFailure(attachment.id);
-            reportProgress(attachment.id, 'upload', 0, fileData.length, 'active');
-            await withRetry(
-                () => withDropboxAccess((token) =>
-                    uploadDropboxFile(
-                        token,
-                        cloudKey,
-                        fileData,
-                        attachment.mimeType || 'application/octet-stream',
-                        dropboxFetcher
-                    )
-                ),
-                {
-                    ...CLOUD_ATTACHMENT_RETRY_OPTIONS,
-                    onRetry: (error, attempt, delayMs) => {
-                        logSyncInfo('Retrying Dropbox attachment upload', {
-                            id: attachment.id,
-                            attempt: String(attempt + 1),
-                            delayMs: String(delayMs),
-                            error: sanitizeLogMessage(error instanceof Error ? error.message : String(error)),
-                        });
-                    },
-                }
-            );
-            attachment.cloudKey = cloudKey;
-            attachment.localStatus = 'available';
-            reportProgress(attachment.id, 'upload', fileData.length, fileData.length, 'completed');
-            return true;
-        },
-        onUploadError: (attachment, error) => {
-            reportProgress(
-                attachment.id,
-                'upload',
-                0,
-                attachment.size ?? 0,
-                'failed',
-                error instanceof Error ? error.message : String(error)
-            );
-            logSyncWarning(`Failed to upload attachment ${attachment.title}`, error);
-        },
-        onDownload: async (attachment) => {
-            if (!attachment.cloudKey) return false;
-            reportProgress(attachment.id, 'download', 0, attachment.size ?? 0, 'active');
-            let fileData: ArrayBuffer;
-            try {
-                fileData = await withRetry(() =>
-                    withDropboxAccess((token) => downloadDropboxFile(token, attachment.cloudKey!, dropboxFetcher))
-                );
-            } catch (error) {
-                if (error instanceof DropboxFileNotFoundError) {
-                    return markAttachmentUnrecoverable(attachment);
-                }
-                throw error;
-            }
-            const bytes = fileData instanceof ArrayBuffer ? new Uint8Array(fileData) : new Uint8Array(fileData as ArrayBuffer);
-            await validateAttachmentHash(attachment, bytes);
-            const filename = attachment.cloudKey.split('/').pop() || `${attachment.id}${extractExtension(attachment.uri)}`;
-            const relativePath = `${LOCAL_ATTACHMENTS_DIR}/${filename}`;
-            await writeAttachmentFileSafely(relativePath, bytes, {
-                baseDir: BaseDirectory.Data,
-                writeFile,
-                rename,
-                remove,
-            });
-            const absolutePath = await join(baseDataDir, relativePath);
-            attachment.uri = absolutePath;
-            const statusChanged = attachment.localStatus !== 'available';
-            if (statusChanged) {
-                attachment.localStatus = 'available';
-            }
-            reportProgress(attachment.id, 'download', bytes.length, bytes.length, 'completed');
-            return statusChanged;
-        },
-        onDownloadError: (attachment, error) => {
-            reportProgress(
-                attachment.id,
-                'download',
-                0,
-                attachment.size ?? 0,
-                'failed',
-                error instanceof Error ? error.message : String(error)
-            );
-            logSyncWarning(`Failed to download attachment ${attachment.title}`, error);
-        },
+async function syncSftpAttachments(appData: AppData): Promise<boolean> {
+    if (!isTauriRuntimeEnv()) return false;
+    // The backend holds the SSH credentials, writes atomically and encrypts when enabled.
+    return await syncNativeAttachments(appData, {
+        label: 'SFTP',
+        upload: (remotePath, localPath) =>
+            tauriInvoke<boolean>('sftp_upload_file', { remotePath, localPath }),
+        download: (remotePath, localPath) =>
+            tauriInvoke<boolean>('sftp_download_file', { remotePath, localPath }),
+        missingIsUnrecoverable: true,
     });
 }
 
@@ -1187,11 +1172,11 @@ async function syncFileAttachments(
         attachmentsById,
         localFileExists,
         onUpload: async (attachment, localPath) => {
-            const cloudKey = buildCloudKey(attachment);
             const fileData = await readLocalFile(localPath);
             const validation = await validateAttachmentForUpload(attachment, fileData.length, FILE_BACKEND_VALIDATION_CONFIG);
             if (!validation.valid) {
                 const failure = handleAttachmentValidationFailure(attachment, validation.error);
+                reportProgress(attachment.id, 'upload', 0, fileData.length, 'failed', failure.message);
                 if (failure.reachedLimit) {
                     logSyncWarning(`${failure.message}; marking attachment unrecoverable`);
                 } else {
@@ -1200,44 +1185,36 @@ async function syncFileAttachments(
                 return failure.mutated;
             }
             clearAttachmentValidationFailure(attachment.id);
+            const cloudKey = await resolveUploadCloudKey(attachment, fileData);
+            reportProgress(attachment.id, 'upload', 0, fileData.length, 'active');
             const targetPath = await join(baseSyncDir, cloudKey);
-            await writeFileSafelyAbsolute(targetPath, fileData, {
-                writeFile,
-                rename,
-                remove,
-            });
+            if (!(await exists(targetPath))) {
+                const payload = await transformSyncAttachmentBytes('seal_sync_attachment_bytes', fileData);
+                await writeFileSafelyAbsolute(targetPath, payload, {
+                    writeFile,
+                    rename,
+                    remove,
+                });
+            }
             attachment.cloudKey = cloudKey;
             attachment.localStatus = 'available';
+            reportProgress(attachment.id, 'upload', fileData.length, fileData.length, 'completed');
             return true;
         },
         onUploadError: (attachment, error) => {
+            reportProgress(
+                attachment.id,
+                'upload',
+                0,
+                attachment.size ?? 0,
+                'failed',
+                error instanceof Error ? error.message : String(error)
+            );
             logSyncWarning(`Failed to copy attachment ${attachment.title} to sync folder`, error);
         },
-        onDownload: async (attachment) => {
-            if (!attachment.cloudKey) return false;
-            const sourcePath = await join(baseSyncDir, attachment.cloudKey);
-            const hasRemote = await exists(sourcePath);
-            if (!hasRemote) return false;
-            const fileData = await readFile(sourcePath);
-            await validateAttachmentHash(attachment, fileData);
-            const filename = attachment.cloudKey.split('/').pop() || `${attachment.id}${extractExtension(attachment.uri)}`;
-            const relativePath = `${LOCAL_ATTACHMENTS_DIR}/${filename}`;
-            await writeAttachmentFileSafely(relativePath, fileData, {
-                baseDir: BaseDirectory.Data,
-                writeFile,
-                rename,
-                remove,
-            });
-            const absolutePath = await join(baseDataDir, relativePath);
-            attachment.uri = absolutePath;
-            const statusChanged = attachment.localStatus !== 'available';
-            if (statusChanged) {
-                attachment.localStatus = 'available';
-            }
-            return statusChanged;
-        },
+        onDownload: adoptCachedAttachment,
         onDownloadError: (attachment, error) => {
-            logSyncWarning(`Failed to copy attachment ${attachment.title} from sync folder`, error);
+            logSyncWarning(`Failed to check cached attachment ${attachment.title}`, error);
         },
     });
 }
@@ -1270,6 +1247,7 @@ export class SyncService {
     private static externalSyncTimer: ReturnType<typeof setTimeout> | null = null;
     private static pendingExternalSyncChange: ExternalSyncChange | null = null;
     private static externalSyncChangeListeners = new Set<(change: ExternalSyncChange | null) => void>();
+    private static configChangeListeners = new Set<() => void>();
 
     static getSyncStatus() {
         return SyncService.syncStatus;
@@ -1291,6 +1269,20 @@ export class SyncService {
         return () => SyncService.externalSyncChangeListeners.delete(listener);
     }
 
+    static subscribeConfigChange(listener: () => void): () => void {
+        SyncService.configChangeListeners.add(listener);
+        return () => SyncService.configChangeListeners.delete(listener);
+    }
+
+    /**
+     * The config files changed outside the app: watch the folder they name now and have open
+     * settings screens read the sync settings again.
+     */
+    static async applyConfigChange(): Promise<void> {
+        await SyncService.startFileWatcher();
+        SyncService.configChangeListeners.forEach((listener) => listener());
+    }
+
     private static notifyExternalSyncChange() {
         SyncService.externalSyncChangeListeners.forEach((listener) => listener(SyncService.pendingExternalSyncChange));
     }
@@ -1469,61 +1461,275 @@ export class SyncService {
         }
     }
 
-    static async getWebDavConfig(options?: { silent?: boolean }): Promise<WebDavConfig> {
-        if (!isTauriRuntimeEnv()) return SyncService.getWebDavConfigLocal();
-        await SyncService.maybeMigrateLegacyLocalStorageToConfig();
-        try {
-            return await tauriInvoke<WebDavConfig>('get_webdav_config');
-        } catch (error) {
-            if (!options?.silent) {
-                reportError('Failed to get WebDAV config', error);
-            }
-            return { url: '', username: '', hasPassword: false };
+    static async getWebDavConfig(options?: { silent?: boolean }): Promise<WebDavConfig> {
+        if (!isTauriRuntimeEnv()) return SyncService.getWebDavConfigLocal();
+        await SyncService.maybeMigrateLegacyLocalStorageToConfig();
+        try {
+            return await tauriInvoke<WebDavConfig>('get_webdav_config');
+        } catch (error) {
+            if (!options?.silent) {
+                reportError('Failed to get WebDAV config', error);
+            }
+            return { url: '', username: '', hasPassword: false };
+        }
+    }
+
+    static async setWebDavConfig(config: { url: string; username?: string; password?: string }): Promise<void> {
+        if (!isTauriRuntimeEnv()) {
+            SyncService.setWebDavConfigLocal(config);
+            return;
+        }
+        try {
+            await tauriInvoke('set_webdav_config', {
+                url: config.url,
+                username: config.username || '',
+                password: config.password || '',
+            });
+        } catch (error) {
+            reportError('Failed to set WebDAV config', error);
+        }
+    }
+
+    static async getCloudConfig(options?: { silent?: boolean }): Promise<CloudConfig> {
+        if (!isTauriRuntimeEnv()) return SyncService.getCloudConfigLocal();
+        await SyncService.maybeMigrateLegacyLocalStorageToConfig();
+        try {
+            return await tauriInvoke<CloudConfig>('get_cloud_config');
+        } catch (error) {
+            if (!options?.silent) {
+                reportError('Failed to get Self-Hosted config', error);
+            }
+            return { url: '', token: '' };
+        }
+    }
+
+    static async setCloudConfig(config: { url: string; token?: string }): Promise<void> {
+        if (!isTauriRuntimeEnv()) {
+            SyncService.setCloudConfigLocal(config);
+            return;
+        }
+        try {
+            await tauriInvoke('set_cloud_config', {
+                url: config.url,
+                token: config.token || '',
+            });
+        } catch (error) {
+            reportError('Failed to set Self-Hosted config', error);
+        }
+    }
+
+    static async getS3Config(options?: { silent?: boolean }): Promise<S3Config> {
+        const empty: S3Config = { endpoint: '', bucket: '', prefix: '', region: '', accessKeyId: '' };
+        if (!isTauriRuntimeEnv()) return empty;
+        try {
+            return await tauriInvoke<S3Config>('get_s3_config');
+        } catch (error) {
+            if (!options?.silent) {
+                reportError('Failed to get S3 config', error);
+            }
+            return empty;
+        }
+    }
+
+    /**
+     * Save the S3 settings. An empty secret key keeps the stored one; an empty bucket clears them all.
+     * Throws when the settings are invalid so the caller can show why.
+     */
+    static async setS3Config(config: S3Config): Promise<void> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('S3 sync is only available in the desktop app.');
+        }
+        await tauriInvoke('set_s3_config', {
+            endpoint: config.endpoint,
+            bucket: config.bucket,
+            prefix: config.prefix,
+            region: config.region,
+            accessKeyId: config.accessKeyId,
+            secretKey: config.secretKey || '',
+        });
+    }
+
+    static async testS3Connection(): Promise<void> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('S3 sync is only available in the desktop app.');
+        }
+        await withTimeout(
+            tauriInvoke<boolean>('test_s3_connection'),
+            S3_TEST_TIMEOUT_MS,
+            'S3 connection test timed out. Please try again.'
+        );
+    }
+
+    static async getGitConfig(options?: { silent?: boolean }): Promise<GitConfig> {
+        const empty: GitConfig = { repoPath: '', remote: '', branch: '' };
+        if (!isTauriRuntimeEnv()) return empty;
+        try {
+            return await tauriInvoke<GitConfig>('get_git_config');
+        } catch (error) {
+            if (!options?.silent) {
+                reportError('Failed to get Git config', error);
+            }
+            return empty;
+        }
+    }
+
+    /**
+     * Save the git repository settings. An empty path clears them all.
+     * Throws when the path is not a git repository so the caller can show why.
+     */
+    static async setGitConfig(config: GitConfig): Promise<void> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Git sync is only available in the desktop app.');
+        }
+        await tauriInvoke('set_git_config', {
+            repoPath: config.repoPath,
+            remote: config.remote,
+            branch: config.branch,
+        });
+    }
+
+    static async testGitConnection(): Promise<void> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Git sync is only available in the desktop app.');
+        }
+        await withTimeout(
+            tauriInvoke<boolean>('test_git_connection'),
+            GIT_TEST_TIMEOUT_MS,
+            'Git connection test timed out. Please try again.'
+        );
+    }
+
+    static async getSftpConfig(options?: { silent?: boolean }): Promise<SftpConfig> {
+        const empty: SftpConfig = { host: '', port: '', username: '', remotePath: '', keyPath: '' };
+        if (!isTauriRuntimeEnv()) return empty;
+        try {
+            return await tauriInvoke<SftpConfig>('get_sftp_config');
+        } catch (error) {
+            if (!options?.silent) {
+                reportError('Failed to get SFTP config', error);
+            }
+            return empty;
+        }
+    }
+
+    /**
+     * Save the SFTP settings. An empty passphrase keeps the stored one; an empty host clears them all.
+     * Throws when the settings are invalid so the caller can show why.
+     */
+    static async setSftpConfig(config: SftpConfig): Promise<void> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('SFTP sync is only available in the desktop app.');
+        }
+        await tauriInvoke('set_sftp_config', {
+            host: config.host,
+            port: config.port,
+            username: config.username,
+            remotePath: config.remotePath,
+            keyPath: config.keyPath,
+            keyPassphrase: config.keyPassphrase || '',
+        });
+    }
+
+    static async testSftpConnection(): Promise<void> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('SFTP sync is only available in the desktop app.');
+        }
+        await withTimeout(
+            tauriInvoke<boolean>('test_sftp_connection'),
+            SFTP_TEST_TIMEOUT_MS,
+            'SFTP connection test timed out. Please try again.'
+        );
+    }
+
+    /** The Dropbox app key when Dropbox is the sync target, so the encryption commands reach it. */
+    private static async getEncryptionDropboxClientId(): Promise<string | undefined> {
+        if (await SyncService.getSyncBackend() !== 'cloud') return undefined;
+        if (await SyncService.getCloudProvider() !== 'dropbox') return undefined;
+        return (await SyncService.getDropboxAppKey()).trim() || undefined;
+    }
+
+    static async getSyncEncryptionStatus(): Promise<SyncEncryptionStatus> {
+        const disabled: SyncEncryptionStatus = { enabled: false, keyId: null, rotationPending: false };
+        if (!isTauriRuntimeEnv()) return disabled;
+        try {
+            return await tauriInvoke<SyncEncryptionStatus>('get_sync_encryption_status');
+        } catch (error) {
+            reportError('Failed to get sync encryption status', error);
+            return disabled;
+        }
+    }
+
+    /**
+     * Encrypt the remote copy with a key derived from `passphrase`, or join encryption another
+     * device already set up. Mobile devices can't read encrypted data and stop syncing.
+     */
+    static async enableSyncEncryption(passphrase: string): Promise<SyncEncryptionStatus> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Sync encryption is only available in the desktop app.');
+        }
+        const dropboxClientId = await SyncService.getEncryptionDropboxClientId();
+        return await tauriInvoke<SyncEncryptionStatus>('enable_sync_encryption', { passphrase, dropboxClientId });
+    }
+
+    static async rotateSyncEncryptionKey(passphrase: string): Promise<SyncEncryptionStatus> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Sync encryption is only available in the desktop app.');
         }
+        const dropboxClientId = await SyncService.getEncryptionDropboxClientId();
+        return await tauriInvoke<SyncEncryptionStatus>('rotate_sync_encryption_key', { passphrase, dropboxClientId });
     }
 
-    static async setWebDavConfig(config: { url: string; username?: string; password?: string }): Promise<void> {
+    /** Decrypt the remote copy back to plaintext and forget the key. */
+    static async disableSyncEncryption(): Promise<SyncEncryptionStatus> {
         if (!isTauriRuntimeEnv()) {
-            SyncService.setWebDavConfigLocal(config);
-            return;
+            throw new Error('Sync encryption is only available in the desktop app.');
         }
+        const dropboxClientId = await SyncService.getEncryptionDropboxClientId();
+        return await tauriInvoke<SyncEncryptionStatus>('disable_sync_encryption', { dropboxClientId });
+    }
+
+    /** Config keys set by a managed policy or an environment variable, mapped to where they come from. */
+    static async getManagedConfigKeys(): Promise<Record<string, string>> {
+        if (!isTauriRuntimeEnv()) return {};
         try {
-            await tauriInvoke('set_webdav_config', {
-                url: config.url,
-                username: config.username || '',
-                password: config.password || '',
-            });
+            return await tauriInvoke<Record<string, string>>('get_managed_config_keys');
         } catch (error) {
-            reportError('Failed to set WebDAV config', error);
+            reportError('Failed to get managed config keys', error);
+            return {};
         }
     }
 
-    static async getCloudConfig(options?: { silent?: boolean }): Promise<CloudConfig> {
-        if (!isTauriRuntimeEnv()) return SyncService.getCloudConfigLocal();
-        await SyncService.maybeMigrateLegacyLocalStorageToConfig();
+    static async getSecretVaultStatus(): Promise<SecretVaultStatus | null> {
+        if (!isTauriRuntimeEnv()) return null;
         try {
-            return await tauriInvoke<CloudConfig>('get_cloud_config');
+            return await tauriInvoke<SecretVaultStatus>('get_secret_vault_status');
         } catch (error) {
-            if (!options?.silent) {
-                reportError('Failed to get Self-Hosted config', error);
-            }
-            return { url: '', token: '' };
+            reportError('Failed to get secret vault status', error);
+            return null;
         }
     }
 
-    static async setCloudConfig(config: { url: string; token?: string }): Promise<void> {
+    /** Unlock the vault, or create it with `create` once the user has confirmed the passphrase. */
+    static async unlockSecretVault(passphrase: string, create: boolean): Promise<SecretVaultStatus> {
         if (!isTauriRuntimeEnv()) {
-            SyncService.setCloudConfigLocal(config);
-            return;
+            throw new Error('The secret vault is only available in the desktop app.');
         }
-        try {
-            await tauriInvoke('set_cloud_config', {
-                url: config.url,
-                token: config.token || '',
-            });
-        } catch (error) {
-            reportError('Failed to set Self-Hosted config', error);
+        return await tauriInvoke<SecretVaultStatus>('unlock_secret_vault', { passphrase, create });
+    }
+
+    static async lockSecretVault(): Promise<SecretVaultStatus> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('The secret vault is only available in the desktop app.');
+        }
+        return await tauriInvoke<SecretVaultStatus>('lock_secret_vault');
+    }
+
+    /** Keep secrets unencrypted in secrets.toml instead of creating a vault. */
+    static async allowPlaintextSecrets(): Promise<SecretVaultStatus> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('The secret vault is only available in the desktop app.');
         }
+        return await tauriInvoke<SecretVaultStatus>('allow_plaintext_secrets');
     }
 
     static async getCloudProvider(): Promise<CloudProvider> {
@@ -1545,7 +1751,7 @@ export class SyncService {
     static async getDropboxRedirectUri(): Promise<string> {
         if (!isTauriRuntimeEnv()) return DROPBOX_REDIRECT_URI_FALLBACK;
         try {
-            return await tauriInvoke<string>('get_dropbox_redirect_uri');
+            return await tauriInvoke<string>('get_oauth_redirect_uri', { provider: 'dropbox' });
         } catch {
             return DROPBOX_REDIRECT_URI_FALLBACK;
         }
@@ -1556,7 +1762,7 @@ export class SyncService {
         if (!normalized) return false;
         if (!isTauriRuntimeEnv()) return false;
         try {
-            return await tauriInvoke<boolean>('is_dropbox_connected', { clientId: normalized });
+            return await tauriInvoke<boolean>('is_oauth_connected', { provider: 'dropbox', clientId: normalized });
         } catch (error) {
             reportError('Failed to check Dropbox connection status', error);
             return false;
@@ -1571,7 +1777,7 @@ export class SyncService {
         if (!isTauriRuntimeEnv()) {
             throw new Error('Dropbox sync is only available in the desktop app.');
         }
-        await tauriInvoke('connect_dropbox', { clientId: normalized });
+        await tauriInvoke('connect_oauth', { provider: 'dropbox', clientId: normalized });
     }
 
     static async disconnectDropbox(clientId: string): Promise<void> {
@@ -1582,10 +1788,10 @@ export class SyncService {
         if (!isTauriRuntimeEnv()) {
             throw new Error('Dropbox sync is only available in the desktop app.');
         }
-        await tauriInvoke('disconnect_dropbox', { clientId: normalized });
+        await tauriInvoke('disconnect_oauth', { provider: 'dropbox', clientId: normalized });
     }
 
-    static async getDropboxAccessToken(clientId: string, options?: { forceRefresh?: boolean }): Promise<string> {
+    static async testDropboxConnection(clientId: string): Promise<void> {
         const normalized = clientId.trim();
         if (!normalized) {
             throw new Error('Dropbox app key is required');
@@ -1593,35 +1799,39 @@ export class SyncService {
         if (!isTauriRuntimeEnv()) {
             throw new Error('Dropbox sync is only available in the desktop app.');
         }
-        return await tauriInvoke<string>('get_dropbox_access_token', {
-            clientId: normalized,
-            forceRefresh: options?.forceRefresh === true,
-        });
+        await withTimeout(
+            tauriInvoke<boolean>('test_dropbox_connection', { clientId: normalized }),
+            DROPBOX_TEST_TIMEOUT_MS,
+            'Dropbox connection test timed out. Please try again.'
+        );
     }
 
-    static async testDropboxConnection(clientId: string): Promise<void> {
+    /**
+     * List stored revisions of the Dropbox data.json, newest first.
+     */
+    static async listDropboxRevisions(clientId: string): Promise<DropboxRevision[]> {
         const normalized = clientId.trim();
         if (!normalized) {
             throw new Error('Dropbox app key is required');
         }
-        const fetcher = await getTauriFetch();
-        const runTest = async (forceRefresh: boolean) => {
-            const accessToken = await SyncService.getDropboxAccessToken(normalized, { forceRefresh });
-            await withTimeout(
-                testDropboxAccess(accessToken, fetcher ?? fetch),
-                DROPBOX_TEST_TIMEOUT_MS,
-                'Dropbox connection test timed out. Please try again.'
-            );
-        };
-        try {
-            await runTest(false);
-        } catch (error) {
-            if (error instanceof DropboxUnauthorizedError) {
-                await runTest(true);
-                return;
-            }
-            throw error;
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Dropbox sync is only available in the desktop app.');
+        }
+        return await tauriInvoke<DropboxRevision[]>('dropbox_list_revisions', { clientId: normalized });
+    }
+
+    /**
+     * Make an older revision the current Dropbox data.json; the next sync merges local data on top of it.
+     */
+    static async restoreDropboxRevision(clientId: string, rev: string): Promise<DropboxRevision> {
+        const normalized = clientId.trim();
+        if (!normalized) {
+            throw new Error('Dropbox app key is required');
+        }
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Dropbox sync is only available in the desktop app.');
         }
+        return await tauriInvoke<DropboxRevision>('dropbox_restore_revision', { clientId: normalized, rev });
     }
 
     /**
@@ -1655,6 +1865,178 @@ export class SyncService {
         }
     }
 
+    /**
+     * Get the layout of the sync folder: a single data.json or one file per item
+     */
+    static async getSyncFileLayout(): Promise<SyncFileLayout> {
+        if (!isTauriRuntimeEnv()) return 'single';
+        try {
+            const layout = await tauriInvoke<string>('get_sync_file_layout');
+            return layout === 'entities' ? 'entities' : 'single';
+        } catch (error) {
+            reportError('Failed to get sync folder layout', error);
+            return 'single';
+        }
+    }
+
+    /**
+     * Convert the sync folder to the given layout
+     */
+    static async setSyncFileLayout(layout: SyncFileLayout): Promise<SyncFileLayout> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Desktop runtime is required for file sync.');
+        }
+        SyncService.ignoreFileEventsUntil = Date.now() + 2000;
+        const result = await tauriInvoke<string>('set_sync_file_layout', { layout });
+        return result === 'entities' ? 'entities' : 'single';
+    }
+
+    /**
+     * Append a finished sync run to the persistent sync journal
+     */
+    static async recordSyncJournal(entry: SyncJournalEntry): Promise<void> {
+        if (!isTauriRuntimeEnv()) return;
+        try {
+            await tauriInvoke<number>('record_sync_journal', { entry });
+        } catch (error) {
+            logSyncWarning('Failed to record sync journal entry', error);
+        }
+    }
+
+    /**
+     * Get the most recent sync runs and the time of the last successful one
+     */
+    static async getSyncJournal(limit?: number): Promise<SyncJournal> {
+        if (!isTauriRuntimeEnv()) return { entries: [], lastSuccessAt: null };
+        try {
+            return await tauriInvoke<SyncJournal>('get_sync_journal', { limit });
+        } catch (error) {
+            logSyncWarning('Failed to read sync journal', error);
+            return { entries: [], lastSuccessAt: null };
+        }
+    }
+
+    /**
+     * Dry run of a sync: read the remote for the configured backend and run the backend's
+     * base-aware merge on it without recording a new base. Nothing is written locally or remotely.
+     */
+    static async previewSync(): Promise<SyncPreview> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Desktop runtime is required for sync preview.');
+        }
+        const backend = await SyncService.getSyncBackend();
+        if (backend === 'off') {
+            throw new Error('Sync is turned off.');
+        }
+        const localData = mergeAppData(await readLocalDataForSync(), getInMemoryAppDataSnapshot());
+        const remoteData = await readRemoteDataForPreview(backend);
+        const cloudProvider = backend === 'cloud' ? await SyncService.getCloudProvider() : 'selfhosted';
+        const dropboxAppKey = cloudProvider === 'dropbox' ? (await SyncService.getDropboxAppKey()).trim() : '';
+        const syncBaseBackend = resolveSyncBaseBackend(backend, cloudProvider);
+        return await previewSyncMerge(localData, remoteData, {
+            mergeData: (local, remote) => mergeWithSyncBase(syncBaseBackend, dropboxAppKey || undefined, local, remote, { dryRun: true }),
+        });
+    }
+
+    /**
+     * Returns a local path for a file attachment. With the file and WebDAV backends,
+     * attachments this device has not seen yet are downloaded here, on first open.
+     */
+    static async ensureAttachmentAvailable(attachment: Attachment): Promise<string> {
+        if (!isTauriRuntimeEnv() || attachment.kind !== 'file' || !attachment.cloudKey) return attachment.uri;
+        const backend = await SyncService.getSyncBackend();
+        if (backend !== 'file' && backend !== 'webdav') return attachment.uri;
+
+        const { BaseDirectory, exists, mkdir, readFile, writeFile, rename, remove } = await import('@tauri-apps/plugin-fs');
+        const { dataDir, join } = await import('@tauri-apps/api/path');
+        const baseDataDir = await dataDir();
+        const rawUri = attachment.uri ? stripFileScheme(attachment.uri) : '';
+        if (rawUri && !/^https?:\/\//i.test(rawUri)) {
+            try {
+                const existsLocally = rawUri.startsWith(baseDataDir)
+                    ? await exists(rawUri.slice(baseDataDir.length).replace(/^[\\/]/, ''), { baseDir: BaseDirectory.Data })
+                    : await exists(rawUri);
+                if (existsLocally) return attachment.uri;
+            } catch (error) {
+                logSyncWarning('Failed to check attachment file', error);
+                return attachment.uri;
+            }
+        }
+        const cloudKey = attachment.cloudKey;
+        const relativePath = getLocalAttachmentPath(attachment, cloudKey);
+        const cachedPath = await join(baseDataDir, relativePath);
+        if (await exists(relativePath, { baseDir: BaseDirectory.Data })) return cachedPath;
+
+        const sizeLimitError = `Attachment exceeds the ${Math.round(DEFAULT_MAX_FILE_SIZE_BYTES / (1024 * 1024))} MB sync limit`;
+        if ((attachment.size ?? 0) > DEFAULT_MAX_FILE_SIZE_BYTES) {
+            throw new Error(sizeLimitError);
+        }
+        if (backend === 'webdav' && getWebdavDownloadBackoff(attachment.id)) {
+            throw new Error('Attachment download failed recently; try again later');
+        }
+
+        reportProgress(attachment.id, 'download', 0, attachment.size ?? 0, 'active');
+        try {
+            let bytes: Uint8Array;
+            if (backend === 'file') {
+                const syncPath = await SyncService.getSyncPath();
+                const baseSyncDir = getFileSyncDir(syncPath, SYNC_FILE_NAME, LEGACY_SYNC_FILE_NAME);
+                if (!baseSyncDir) throw new Error('Sync folder is not configured');
+                const sourcePath = await join(baseSyncDir, cloudKey);
+                if (!(await exists(sourcePath))) {
+                    throw new Error(`Attachment ${cloudKey} was not found in the sync folder`);
+                }
+                bytes = await transformSyncAttachmentBytes('open_sync_attachment_bytes', await readFile(sourcePath));
+            } else {
+                const webDavConfig = await SyncService.getWebDavConfig();
+                if (!webDavConfig.url) throw new Error('WebDAV URL not configured');
+                // The backend only writes inside the attachments folder and opens sealed files itself.
+                const partialRelative = `${relativePath}.partial`;
+                const partialPath = await join(baseDataDir, partialRelative);
+                await mkdir(LOCAL_ATTACHMENTS_DIR, { baseDir: BaseDirectory.Data, recursive: true });
+                const found = await withNativeTransferProgress(attachment.id, () => tauriInvoke<boolean>('webdav_download_file', {
+                    remotePath: cloudKey,
+                    localPath: partialPath,
+                    progressId: attachment.id,
+                }));
+                if (!found) throw new Error(`Attachment ${cloudKey} was not found on the server`);
+                try {
+                    bytes = await readFile(partialRelative, { baseDir: BaseDirectory.Data });
+                } finally {
+                    await remove(partialRelative, { baseDir: BaseDirectory.Data }).catch(() => undefined);
+                }
+            }
+            if (bytes.length > DEFAULT_MAX_FILE_SIZE_BYTES) {
+                throw new Error(sizeLimitError);
+            }
+            await validateAttachmentHash(attachment, bytes);
+            await mkdir(LOCAL_ATTACHMENTS_DIR, { baseDir: BaseDirectory.Data, recursive: true });
+            await writeAttachmentFileSafely(relativePath, bytes, {
+                baseDir: BaseDirectory.Data,
+                writeFile,
+                rename,
+                remove,
+            });
+            webdavDownloadBackoff.deleteEntry(attachment.id);
+            reportProgress(attachment.id, 'download', bytes.length, bytes.length, 'completed');
+            return cachedPath;
+        } catch (error) {
+            if (backend === 'webdav') {
+                setWebdavDownloadBackoff(attachment.id, error);
+            }
+            reportProgress(
+                attachment.id,
+                'download',
+                0,
+                attachment.size ?? 0,
+                'failed',
+                error instanceof Error ? error.message : String(error)
+            );
+            logSyncWarning(`Failed to download attachment ${attachment.title}`, error);
+            throw error;
+        }
+    }
+
     private static async markSyncWrite(data: AppData) {
         const hash = await hashString(toStableJson(data));
         SyncService.lastWrittenHash = hash;
@@ -1687,13 +2069,18 @@ export class SyncService {
                 await flushPendingSave();
                 const localData = await injectExternalCalendars(await readLocalDataForSync());
                 const sanitized = sanitizeAppDataForRemote(localData);
+                // Writing against the version just read replaces the folder instead of merging the external change in.
+                const { version } = await tauriInvoke<SyncFolderRead>('read_sync_file');
                 await SyncService.markSyncWrite(sanitized);
-                await tauriInvoke('write_sync_file', { data: sanitized });
+                const result = await tauriInvoke<SyncPutResult>('write_sync_file', { data: sanitized, expectedVersion: version });
+                if (result?.status === 'conflict') {
+                    throw new SyncConflictError('Sync folder changed again before it could be replaced. Please try again.');
+                }
                 return await SyncService.performSync();
             }
 
             await flushPendingSave();
-            const externalData = normalizeAppData(await tauriInvoke<AppData>('read_sync_file'));
+            const externalData = normalizeAppData((await tauriInvoke<SyncFolderRead>('read_sync_file')).data);
             await tauriInvoke('save_data', { data: externalData });
             await useTaskStore.getState().fetchData({ silent: true });
             const now = new Date().toISOString();
@@ -1726,15 +2113,20 @@ export class SyncService {
         }
     }
 
-    private static async handleFileChange(paths: string[]) {
+    /**
+     * React to sync files written by another device, as reported by the backend scheduler
+     */
+    static async handleSyncFolderChange(paths: string[]) {
         if (!isTauriRuntimeEnv()) return;
         if (Date.now() < SyncService.ignoreFileEventsUntil) return;
 
-        const hasSyncFile = paths.some((path) => isSyncFilePath(path, SYNC_FILE_NAME, LEGACY_SYNC_FILE_NAME));
+        const hasSyncFile = paths.some((path) => (
+            isSyncFilePath(path, SYNC_FILE_NAME, LEGACY_SYNC_FILE_NAME) || isEntitySyncFilePath(path)
+        ));
         if (!hasSyncFile) return;
 
         try {
-            const syncData = await tauriInvoke<AppData>('read_sync_file');
+            const { data: syncData } = await tauriInvoke<SyncFolderRead>('read_sync_file');
             const normalized = normalizeAppData(syncData);
             const hash = await hashString(toStableJson(normalized));
             if (hash === SyncService.lastWrittenHash) {
@@ -1803,6 +2195,10 @@ export class SyncService {
         return null;
     }
 
+    /**
+     * Watch the file sync folder and pass its changes to the backend scheduler, which
+     * debounces them and holds back those that arrive while a sync is running.
+     */
     static async startFileWatcher(): Promise<void> {
         if (!isTauriRuntimeEnv()) return;
         const backend = await SyncService.getSyncBackend();
@@ -1824,6 +2220,7 @@ export class SyncService {
 
         try {
             const { watch } = await import('@tauri-apps/plugin-fs');
+            // Recursive so per-entity item files in the collection folders are seen too.
             const unwatch = await watch(watchPath, (event: any) => {
                 const paths = Array.isArray(event?.paths)
                     ? event.paths
@@ -1831,8 +2228,10 @@ export class SyncService {
                         ? [event.path]
                         : [];
                 if (paths.length === 0) return;
-                void SyncService.handleFileChange(paths);
-            });
+                tauriInvoke('report_sync_folder_change', { paths }).catch((error) => {
+                    logSyncWarning('Failed to report sync folder change', error);
+                });
+            }, { recursive: true });
             SyncService.fileWatcherStop = SyncService.resolveUnwatch(unwatch);
             SyncService.fileWatcherPath = watchPath;
             SyncService.fileWatcherBackend = backend;
@@ -1859,6 +2258,36 @@ export class SyncService {
         SyncService.setPendingExternalSyncChange(null);
     }
 
+    /**
+     * Tell the backend scheduler that tasks or projects changed so it can queue a debounced sync
+     */
+    static reportLocalChange(): void {
+        if (!isTauriRuntimeEnv()) return;
+        tauriInvoke('report_local_change').catch((error) => {
+            logSyncWarning('Failed to report local change', error);
+        });
+    }
+
+    /**
+     * Get the minutes between background syncs; 0 means only change-triggered syncs
+     */
+    static async getSyncInterval(): Promise<number> {
+        if (!isTauriRuntimeEnv()) return 0;
+        try {
+            return await tauriInvoke<number>('get_sync_interval_minutes');
+        } catch (error) {
+            reportError('Failed to get sync interval', error);
+            return 0;
+        }
+    }
+
+    static async setSyncInterval(minutes: number): Promise<number> {
+        if (!isTauriRuntimeEnv()) {
+            throw new Error('Desktop runtime is required for background sync.');
+        }
+        return await tauriInvoke<number>('set_sync_interval_minutes', { minutes: Math.max(0, Math.round(minutes)) });
+    }
+
     static async cleanupAttachmentsNow(): Promise<void> {
         if (!isTauriRuntimeEnv()) return;
         const backend = await SyncService.getSyncBackend();
@@ -1916,6 +2345,11 @@ export class SyncService {
             resolveInFlight?.(value);
         };
         SyncService.syncInFlight = inFlightPromise;
+        if (isTauriRuntimeEnv()) {
+            tauriInvoke('report_sync_run_started').catch((error) => {
+                logSyncWarning('Failed to report sync start', error);
+            });
+        }
         let step = 'init';
         let backend: SyncBackend = 'off';
         let syncUrl: string | undefined;
@@ -1923,6 +2357,11 @@ export class SyncService {
         let networkWentOffline = false;
         let removeNetworkListener: (() => void) | null = null;
         const requestAbortController = new AbortController();
+        const journalStartedAt = new Date().toISOString();
+        let journalBytes = 0;
+        let journalLocalData: AppData | null = null;
+        let journalChanges = { added: 0, updated: 0, deleted: 0, conflicts: 0 };
+        let journalSkipped = false;
 
         SyncService.updateSyncStatus({
             inFlight: true,
@@ -1941,7 +2380,7 @@ export class SyncService {
             const createFetchWithAbort = (baseFetch: typeof fetch): typeof fetch =>
                 createAbortableFetch(baseFetch, { baseSignal: requestAbortController.signal });
             const ensureNetworkStillAvailable = () => {
-                if (backend !== 'cloud' && backend !== 'webdav') return;
+                if (backend !== 'cloud' && backend !== 'webdav' && backend !== 's3' && backend !== 'sftp') return;
                 if (
                     networkWentOffline
                     || (typeof navigator !== 'undefined' && navigator.onLine === false)
@@ -1960,7 +2399,7 @@ export class SyncService {
             if (backend === 'off') {
                 return { success: true };
             }
-            if ((backend === 'cloud' || backend === 'webdav') && typeof window !== 'undefined') {
+            if ((backend === 'cloud' || backend === 'webdav' || backend === 's3' || backend === 'sftp') && typeof window !== 'undefined') {
                 const handleOffline = () => {
                     networkWentOffline = true;
                     requestAbortController.abort();
@@ -1979,7 +2418,7 @@ export class SyncService {
                     logSyncWarning('Failed to create pre-sync snapshot', error);
                 }
             }
-            if ((backend === 'cloud' || backend === 'webdav') && typeof navigator !== 'undefined' && navigator.onLine === false) {
+            if ((backend === 'cloud' || backend === 'webdav' || backend === 's3' || backend === 'sftp') && typeof navigator !== 'undefined' && navigator.onLine === false) {
                 throw new Error('Offline: network connection is unavailable for remote sync.');
             }
             const webdavConfig = backend === 'webdav' ? await SyncService.getWebDavConfig() : null;
@@ -1993,33 +2432,25 @@ export class SyncService {
             if (backend === 'cloud' && cloudProvider === 'dropbox' && !dropboxAppKey) {
                 throw new Error('Dropbox app key is not configured');
             }
-            let dropboxDataRev: string | null = null;
-            let cachedDropboxAccessToken: string | null = null;
-            const resolveDropboxAccessToken = async (forceRefresh = false): Promise<string> => {
-                if (!dropboxAppKey) {
-                    throw new Error('Dropbox app key is not configured');
-                }
-                if (!cachedDropboxAccessToken || forceRefresh) {
-                    cachedDropboxAccessToken = await SyncService.getDropboxAccessToken(dropboxAppKey, { forceRefresh });
-                }
-                return cachedDropboxAccessToken;
-            };
-            const runDropboxWithRetry = async <T>(operation: (token: string) => Promise<T>): Promise<T> => {
-                try {
-                    const token = await resolveDropboxAccessToken(false);
-                    return await operation(token);
-                } catch (error) {
-                    if (error instanceof DropboxUnauthorizedError) {
-                        const refreshed = await resolveDropboxAccessToken(true);
-                        return await operation(refreshed);
-                    }
-                    throw error;
-                }
-            };
+            const s3Config = backend === 's3' ? await SyncService.getS3Config() : null;
+            if (backend === 's3' && !s3Config?.bucket) {
+                throw new Error('S3 bucket is not configured');
+            }
+            const sftpConfig = backend === 'sftp' ? await SyncService.getSftpConfig() : null;
+            if (backend === 'sftp' && !sftpConfig?.host) {
+                throw new Error('SFTP host is not configured');
+            }
+            const gitConfig = backend === 'git' ? await SyncService.getGitConfig() : null;
+            if (backend === 'git' && !gitConfig?.repoPath) {
+                throw new Error('Git repository is not configured');
+            }
             const syncPath = backend === 'file' ? await SyncService.getSyncPath() : '';
             const fileBaseDir = backend === 'file' ? getFileSyncDir(syncPath, SYNC_FILE_NAME, LEGACY_SYNC_FILE_NAME) : '';
+            const syncBaseBackend = resolveSyncBaseBackend(backend, cloudProvider);
             let preSyncedLocalData: AppData | null = null;
             let remoteDataForCompare: AppData | null = null;
+            // Version of the remote as last read; the backend refuses a put once the remote moved on.
+            let remoteVersion: string | null = null;
             let webdavRemoteCorrupted = false;
             const ensureLocalSnapshotFresh = () => {
                 if (useTaskStore.getState().lastDataChangeAt > localSnapshotChangeAt) {
@@ -2030,15 +2461,14 @@ export class SyncService {
             };
 
             // Pre-sync local attachments so cloudKeys exist before writing remote data.
-            if (isTauriRuntimeEnv() && (backend === 'webdav' || backend === 'file' || backend === 'cloud')) {
+            if (isTauriRuntimeEnv() && (backend === 'webdav' || backend === 'file' || backend === 'cloud' || backend === 's3' || backend === 'sftp')) {
                 setStep('attachments_prepare');
                 try {
                     const localData = await readLocalDataForSync();
                     let preMutated = false;
                     if (backend === 'webdav' && webdavConfig?.url) {
                         ensureNetworkStillAvailable();
-                        const baseUrl = getBaseSyncUrl(webdavConfig.url);
-                        const syncedData = await syncAttachments(localData, webdavConfig, baseUrl);
+                        const syncedData = await syncAttachments(localData, webdavConfig);
                         preMutated = syncedData !== null;
                         if (syncedData) {
                             preSyncedLocalData = syncedData;
@@ -2047,11 +2477,16 @@ export class SyncService {
                         preMutated = await syncFileAttachments(localData, fileBaseDir);
                     } else if (backend === 'cloud' && cloudProvider === 'selfhosted' && cloudConfig?.url) {
                         ensureNetworkStillAvailable();
-                        const baseUrl = getCloudBaseUrl(cloudConfig.url);
-                        preMutated = await syncCloudAttachments(localData, cloudConfig, baseUrl);
+                        preMutated = await syncCloudAttachments(localData, cloudConfig);
                     } else if (backend === 'cloud' && cloudProvider === 'dropbox') {
                         ensureNetworkStillAvailable();
-                        preMutated = await syncDropboxAttachments(localData, resolveDropboxAccessToken);
+                        preMutated = await syncDropboxAttachments(localData, dropboxAppKey);
+                    } else if (backend === 's3') {
+                        ensureNetworkStillAvailable();
+                        preMutated = await syncS3Attachments(localData);
+                    } else if (backend === 'sftp') {
+                        ensureNetworkStillAvailable();
+                        preMutated = await syncSftpAttachments(localData);
                     }
                     if (preMutated) {
                         ensureLocalSnapshotFresh();
@@ -2074,12 +2509,13 @@ export class SyncService {
                                 throw new Error('WebDAV URL not configured');
                             }
                             syncUrl = webdavConfig.url;
-                            const data = await withRetry(
-                                () => tauriInvoke<AppData>('webdav_get_json'),
+                            const { data, version } = await withRetry(
+                                () => tauriInvoke<RemoteSyncRead>('webdav_get_json'),
                                 WEBDAV_READ_RETRY_OPTIONS,
                             );
                             webdavRemoteCorrupted = false;
                             remoteDataForCompare = data ?? null;
+                            remoteVersion = version;
                             return data;
                         }
                         if (!webdavConfig?.url) {
@@ -2116,28 +2552,64 @@ export class SyncService {
                         }
                         const normalizedUrl = normalizeCloudUrl(cloudConfig.url);
                         syncUrl = normalizedUrl;
+                        if (isTauriRuntimeEnv()) {
+                            const { data, version } = await tauriInvoke<RemoteSyncRead>('cloud_get_json');
+                            remoteDataForCompare = data ?? null;
+                            remoteVersion = version;
+                            return data;
+                        }
                         const fetcher = createFetchWithAbort((await getTauriFetch()) ?? fetch);
-                        const data = await cloudGetJson<AppData>(normalizedUrl, { token: cloudConfig.token, fetcher });
+                        const { data, etag } = await cloudGetJsonWithEtag<AppData>(normalizedUrl, { token: cloudConfig.token ?? '', fetcher });
                         remoteDataForCompare = data ?? null;
+                        remoteVersion = etag;
                         return data;
                     }
                     if (!dropboxAppKey) {
                         throw new Error('Dropbox app key is not configured');
                     }
                     syncUrl = 'dropbox:///Apps/Mindwtr/data.json';
-                    const fetcher = createFetchWithAbort((await getTauriFetch()) ?? fetch);
-                    const remote = await runDropboxWithRetry((token) =>
-                        downloadDropboxAppData(token, fetcher)
-                    );
-                    dropboxDataRev = remote.rev;
-                    remoteDataForCompare = remote.data ?? null;
-                    return remote.data;
+                    const { data, version } = await tauriInvoke<RemoteSyncRead>('dropbox_get_json', { clientId: dropboxAppKey });
+                    remoteDataForCompare = data ?? null;
+                    remoteVersion = version;
+                    return data;
+                }
+                if (backend === 's3') {
+                    if (!isTauriRuntimeEnv()) {
+                        throw new Error('S3 sync is only available in the desktop app.');
+                    }
+                    syncUrl = buildS3SyncUrl(s3Config);
+                    const { data, version } = await tauriInvoke<RemoteSyncRead>('s3_get_json');
+                    remoteDataForCompare = data ?? null;
+                    remoteVersion = version;
+                    return data;
+                }
+                if (backend === 'sftp') {
+                    if (!isTauriRuntimeEnv()) {
+                        throw new Error('SFTP sync is only available in the desktop app.');
+                    }
+                    const remoteDir = sftpConfig?.remotePath || 'mindwtr';
+                    syncUrl = `sftp://${sftpConfig?.username ?? ''}@${sftpConfig?.host ?? ''}/${remoteDir}/${SYNC_FILE_NAME}`;
+                    const { data, version } = await tauriInvoke<RemoteSyncRead>('sftp_get_json');
+                    remoteDataForCompare = data ?? null;
+                    remoteVersion = version;
+                    return data;
+                }
+                if (backend === 'git') {
+                    if (!isTauriRuntimeEnv()) {
+                        throw new Error('Git sync is only available in the desktop app.');
+                    }
+                    syncUrl = `git://${gitConfig?.repoPath ?? ''}/${SYNC_FILE_NAME}`;
+                    const { data, version } = await tauriInvoke<RemoteSyncRead>('git_get_json');
+                    remoteDataForCompare = data ?? null;
+                    remoteVersion = version;
+                    return data;
                 }
                 if (!isTauriRuntimeEnv()) {
                     throw new Error('File sync is not available in the web app.');
                 }
-                const data = await tauriInvoke<AppData>('read_sync_file');
+                const { data, version } = await tauriInvoke<SyncFolderRead>('read_sync_file');
                 remoteDataForCompare = data ?? null;
+                remoteVersion = version;
                 return data;
             };
 
@@ -2149,15 +2621,30 @@ export class SyncService {
                     ? sanitizeAppDataForRemote(remoteDataForCompare)
                     : null;
                 if (remoteSanitized && areSyncPayloadsEqual(remoteSanitized, sanitized)) {
+                    if (isTauriRuntimeEnv()) {
+                        // Nothing to write, but this is still the state both sides agree on.
+                        await tauriInvoke<boolean>('commit_sync_base', {
+                            backend: syncBaseBackend,
+                            clientId: dropboxAppKey || undefined,
+                            data: sanitized,
+                        }).catch((error) => logSyncWarning('Failed to record sync base', error));
+                    }
                     return;
                 }
+                journalBytes += measureSyncPayloadBytes(sanitized);
                 if (backend === 'webdav') {
                     if (isTauriRuntimeEnv()) {
                         if (webdavRemoteCorrupted) {
                             logSyncInfo('Repairing corrupted WebDAV data.json with current merged data');
                         }
-                        await tauriInvoke('webdav_put_json', { data: sanitized });
-                        remoteDataForCompare = sanitized;
+                        const result = await tauriInvoke<SyncPutResult>('webdav_put_json', {
+                            data: sanitized,
+                            expectedVersion: remoteVersion,
+                        });
+                        if (result?.status === 'conflict') {
+                            throw new SyncConflictError('WebDAV data kept changing during sync. Please run Sync again.');
+                        }
+                        remoteDataForCompare = result?.data ?? sanitized;
                         webdavRemoteCorrupted = false;
                         return;
                     }
@@ -2174,34 +2661,80 @@ export class SyncService {
                 }
                 if (backend === 'cloud') {
                     if (cloudProvider === 'selfhosted') {
+                        if (isTauriRuntimeEnv()) {
+                            const result = await tauriInvoke<SyncPutResult>('cloud_put_json', {
+                                data: sanitized,
+                                expectedVersion: remoteVersion,
+                            });
+                            if (result?.status === 'conflict') {
+                                throw new SyncConflictError('Cloud data kept changing during sync. Please run Sync again.');
+                            }
+                            remoteDataForCompare = result?.data ?? sanitized;
+                            return;
+                        }
                         const { url, token } = await SyncService.getCloudConfig();
                         const normalizedUrl = normalizeCloudUrl(url);
                         const fetcher = createFetchWithAbort((await getTauriFetch()) ?? fetch);
-                        await cloudPutJson(normalizedUrl, sanitized, { token, fetcher });
+                        await cloudPutJson(normalizedUrl, sanitized, { token: token ?? '', fetcher, ifMatch: remoteVersion });
                         remoteDataForCompare = sanitized;
                         return;
                     }
                     if (!dropboxAppKey) {
                         throw new Error('Dropbox app key is not configured');
                     }
-                    const fetcher = createFetchWithAbort((await getTauriFetch()) ?? fetch);
-                    try {
-                        const uploaded = await runDropboxWithRetry((token) =>
-                            uploadDropboxAppData(token, sanitized, dropboxDataRev, fetcher)
-                        );
-                        dropboxDataRev = uploaded.rev;
-                        remoteDataForCompare = sanitized;
-                        return;
-                    } catch (error) {
-                        if (error instanceof DropboxConflictError) {
-                            throw new Error('Dropbox changed during sync. Please run Sync again.');
-                        }
-                        throw error;
+                    const result = await tauriInvoke<SyncPutResult>('dropbox_put_json', {
+                        clientId: dropboxAppKey,
+                        data: sanitized,
+                        expectedVersion: remoteVersion,
+                    });
+                    if (result?.status === 'conflict') {
+                        throw new SyncConflictError('Dropbox kept changing during sync. Please run Sync again.');
+                    }
+                    remoteDataForCompare = result?.data ?? sanitized;
+                    return;
+                }
+                if (backend === 's3') {
+                    const result = await tauriInvoke<SyncPutResult>('s3_put_json', {
+                        data: sanitized,
+                        expectedVersion: remoteVersion,
+                    });
+                    if (result?.status === 'conflict') {
+                        throw new SyncConflictError('S3 data kept changing during sync. Please run Sync again.');
+                    }
+                    remoteDataForCompare = result?.data ?? sanitized;
+                    return;
+                }
+                if (backend === 'sftp') {
+                    const result = await tauriInvoke<SyncPutResult>('sftp_put_json', {
+                        data: sanitized,
+                        expectedVersion: remoteVersion,
+                    });
+                    if (result?.status === 'conflict') {
+                        throw new SyncConflictError('SFTP data kept changing during sync. Please run Sync again.');
+                    }
+                    remoteDataForCompare = result?.data ?? sanitized;
+                    return;
+                }
+                if (backend === 'git') {
+                    const result = await tauriInvoke<SyncPutResult>('git_put_json', {
+                        data: sanitized,
+                        expectedVersion: remoteVersion,
+                    });
+                    if (result?.status === 'conflict') {
+                        throw new SyncConflictError('Git remote kept changing during sync. Please run Sync again.');
                     }
+                    remoteDataForCompare = result?.data ?? sanitized;
+                    return;
                 }
                 await SyncService.markSyncWrite(sanitized);
-                await tauriInvoke('write_sync_file', { data: sanitized });
-                remoteDataForCompare = sanitized;
+                const result = await tauriInvoke<SyncPutResult>('write_sync_file', {
+                    data: sanitized,
+                    expectedVersion: remoteVersion,
+                });
+                if (result?.status === 'conflict') {
+                    throw new SyncConflictError('Sync folder kept changing during sync. Please run Sync again.');
+                }
+                remoteDataForCompare = result?.data ?? sanitized;
             };
 
             const syncResult = await performSyncCycle({
@@ -2212,9 +2745,14 @@ export class SyncService {
                         : mergeAppData(await readLocalDataForSync(), inMemorySnapshot);
                     const data = await injectExternalCalendars(baseData);
                     localSnapshotChangeAt = useTaskStore.getState().lastDataChangeAt;
+                    journalLocalData = data;
+                    return data;
+                },
+                readRemote: async () => {
+                    const data = await readRemoteDataByBackend();
+                    journalBytes += measureSyncPayloadBytes(data);
                     return data;
                 },
-                readRemote: readRemoteDataByBackend,
                 writeLocal: async (data) => {
                     ensureLocalSnapshotFresh();
                     if (isTauriRuntimeEnv()) {
@@ -2227,6 +2765,9 @@ export class SyncService {
                     ensureLocalSnapshotFresh();
                     await writeRemoteDataByBackend(data);
                 },
+                mergeData: isTauriRuntimeEnv()
+                    ? (local, remote) => mergeWithSyncBase(syncBaseBackend, dropboxAppKey || undefined, local, remote)
+                    : undefined,
                 onStep: (next) => {
                     setStep(next);
                 },
@@ -2252,6 +2793,10 @@ export class SyncService {
                 + (stats.projects.timestampAdjustments || 0)
                 + (stats.sections.timestampAdjustments || 0)
                 + (stats.areas.timestampAdjustments || 0);
+            journalChanges = {
+                ...(journalLocalData ? summarizeSyncChanges(journalLocalData, mergedData) : journalChanges),
+                conflicts: conflictCount,
+            };
             if (isTauriRuntimeEnv() && (conflictCount > 0 || maxClockSkewMs > CLOCK_SKEW_THRESHOLD_MS || timestampAdjustments > 0)) {
                 const conflictSamples = [
                     ...(stats.tasks.conflictIds || []),
@@ -2274,17 +2819,16 @@ export class SyncService {
             }
             ensureLocalSnapshotFresh();
 
-            if ((backend === 'webdav' || backend === 'file' || backend === 'cloud') && isTauriRuntimeEnv()) {
+            if ((backend === 'webdav' || backend === 'file' || backend === 'cloud' || backend === 's3' || backend === 'sftp') && isTauriRuntimeEnv()) {
                 setStep('attachments');
                 try {
                     ensureLocalSnapshotFresh();
                     if (backend === 'webdav') {
                         ensureNetworkStillAvailable();
                         const config = await SyncService.getWebDavConfig();
-                        const baseUrl = config.url ? getBaseSyncUrl(config.url) : '';
-                        if (baseUrl) {
+                        if (config.url) {
                             const candidateData = cloneAppData(mergedData);
-                            const syncedData = await syncAttachments(candidateData, config, baseUrl);
+                            const syncedData = await syncAttachments(candidateData, config);
                             if (syncedData) {
                                 mergedData = syncedData;
                                 await tauriInvoke('save_data', { data: mergedData });
@@ -2303,10 +2847,9 @@ export class SyncService {
                         ensureNetworkStillAvailable();
                         if (cloudProvider === 'selfhosted') {
                             const config = cloudConfig ?? await SyncService.getCloudConfig();
-                            const baseUrl = config.url ? getCloudBaseUrl(config.url) : '';
-                            if (baseUrl) {
+                            if (config.url) {
                                 const candidateData = cloneAppData(mergedData);
-                                const mutated = await syncCloudAttachments(candidateData, config, baseUrl);
+                                const mutated = await syncCloudAttachments(candidateData, config);
                                 if (mutated) {
                                     mergedData = candidateData;
                                     await tauriInvoke('save_data', { data: mergedData });
@@ -2314,12 +2857,28 @@ export class SyncService {
                             }
                         } else if (cloudProvider === 'dropbox') {
                             const candidateData = cloneAppData(mergedData);
-                            const mutated = await syncDropboxAttachments(candidateData, resolveDropboxAccessToken);
+                            const mutated = await syncDropboxAttachments(candidateData, dropboxAppKey);
                             if (mutated) {
                                 mergedData = candidateData;
                                 await tauriInvoke('save_data', { data: mergedData });
                             }
                         }
+                    } else if (backend === 's3') {
+                        ensureNetworkStillAvailable();
+                        const candidateData = cloneAppData(mergedData);
+                        const mutated = await syncS3Attachments(candidateData);
+                        if (mutated) {
+                            mergedData = candidateData;
+                            await tauriInvoke('save_data', { data: mergedData });
+                        }
+                    } else if (backend === 'sftp') {
+                        ensureNetworkStillAvailable();
+                        const candidateData = cloneAppData(mergedData);
+                        const mutated = await syncSftpAttachments(candidateData);
+                        if (mutated) {
+                            mergedData = candidateData;
+                            await tauriInvoke('save_data', { data: mergedData });
+                        }
                     }
                 } catch (error) {
                     if (error instanceof LocalSyncAbort) {
@@ -2364,6 +2923,7 @@ export class SyncService {
 
         const resultPromise = runSync().catch(async (error) => {
             if (error instanceof LocalSyncAbort) {
+                journalSkipped = true;
                 return { success: true };
             }
             logSyncWarning('Sync failed', error);
@@ -2403,6 +2963,18 @@ export class SyncService {
         });
 
         const result = await resultPromise;
+        const journalBackend = backend as SyncBackend;
+        if (journalBackend !== 'off' && !journalSkipped) {
+            await SyncService.recordSyncJournal({
+                startedAt: journalStartedAt,
+                finishedAt: new Date().toISOString(),
+                backend: journalBackend,
+                direction: 'merge',
+                bytes: journalBytes,
+                ...journalChanges,
+                error: result.success ? null : result.error ?? 'Sync failed',
+            });
+        }
         try {
             const releaseNetworkListener = removeNetworkListener as (() => void) | null;
             removeNetworkListener = null;
@@ -2413,6 +2985,11 @@ export class SyncService {
             logSyncWarning('Failed to unsubscribe network listener after sync', error);
         }
         SyncService.syncInFlight = null;
+        if (isTauriRuntimeEnv()) {
+            tauriInvoke('report_sync_run_finished', { success: result.success }).catch((error) => {
+                logSyncWarning('Failed to report sync result', error);
+            });
+        }
         SyncService.updateSyncStatus({
             inFlight: false,
             step: null,
@@ -2462,4 +3039,7 @@ export const __syncServiceTestUtils = {
     getAttachmentValidationFailureAttempts(attachmentId: string) {
         return getAttachmentValidationFailureAttempts(attachmentId);
     },
+    resolveUploadCloudKey(attachment: Attachment, bytes: Uint8Array) {
+        return resolveUploadCloudKey(attachment, bytes);
+    },
 };
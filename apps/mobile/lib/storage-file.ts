@@ -2,7 +2,7 @@ import * as DocumentPicker from 'expo-document-picker';
 import * as Sharing from 'expo-sharing';
 import * as FileSystem from 'expo-file-system/legacy';
 import { Directory as ExpoDirectory, File as ExpoFile } from 'expo-file-system';
-import { AppData } from '@mindwtr/core';
+import { AppData, withSyncIntegrity } from '@mindwtr/core';
 import { Platform } from 'react-native';
 import { logError, logInfo, logWarn } from './app-log';
 
@@ -505,7 +505,8 @@ export const readSyncFile = async (fileUri: string): Promise<AppData | null> =>
 // Write merged data back to sync file
 export const writeSyncFile = async (fileUri: string, data: AppData): Promise<void> => {
     try {
-        const content = JSON.stringify(data, null, 2);
+        // Desktop treats a data.json without the integrity block as damaged.
+        const content = JSON.stringify(withSyncIntegrity(data), null, 2);
         const resolvedUri = await resolveSyncFileUri(fileUri, { createIfMissing: true });
         // SAF URIs (content://) require special handling on Android
         if (resolvedUri.startsWith('content://') && StorageAccessFramework) {
@@ -1,7 +1,7 @@
 import AsyncStorage from '@react-native-async-storage/async-storage';
 import { Platform } from 'react-native';
 import Constants from 'expo-constants';
-import { AppData, Attachment, MergeStats, useTaskStore, webdavGetJson, webdavPutJson, cloudGetJson, cloudPutJson, flushPendingSave, performSyncCycle, findOrphanedAttachments, removeOrphanedAttachmentsFromData, removeAttachmentsByIdFromData, webdavDeleteFile, cloudDeleteFile, CLOCK_SKEW_THRESHOLD_MS, appendSyncHistory, withRetry, isRetryableWebdavReadError, isWebdavInvalidJsonError, normalizeWebdavUrl, normalizeCloudUrl, sanitizeAppDataForRemote, areSyncPayloadsEqual, assertNoPendingAttachmentUploads, injectExternalCalendars as injectExternalCalendarsForSync, persistExternalCalendars as persistExternalCalendarsForSync, mergeAppData, cloneAppData, LocalSyncAbort, getInMemoryAppDataSnapshot, shouldRunAttachmentCleanup, createAbortableFetch, normalizeCloudProvider as normalizeCoreCloudProvider, CLOUD_PROVIDER_DROPBOX, CLOUD_PROVIDER_SELF_HOSTED, type CloudProvider } from '@mindwtr/core';
+import { AppData, Attachment, MergeStats, useTaskStore, webdavGetJson, webdavPutJson, cloudGetJsonWithEtag, cloudPutJson, flushPendingSave, performSyncCycle, findOrphanedAttachments, removeOrphanedAttachmentsFromData, removeAttachmentsByIdFromData, webdavDeleteFile, cloudDeleteFile, CLOCK_SKEW_THRESHOLD_MS, appendSyncHistory, withRetry, isRetryableWebdavReadError, isWebdavInvalidJsonError, normalizeWebdavUrl, normalizeCloudUrl, sanitizeAppDataForRemote, areSyncPayloadsEqual, assertNoPendingAttachmentUploads, injectExternalCalendars as injectExternalCalendarsForSync, persistExternalCalendars as persistExternalCalendarsForSync, mergeAppData, cloneAppData, LocalSyncAbort, getInMemoryAppDataSnapshot, shouldRunAttachmentCleanup, createAbortableFetch, normalizeCloudProvider as normalizeCoreCloudProvider, CLOUD_PROVIDER_DROPBOX, CLOUD_PROVIDER_SELF_HOSTED, type CloudProvider } from '@mindwtr/core';
 import { mobileStorage } from './storage-adapter';
 import { logInfo, logSyncError, logWarn, sanitizeLogMessage } from './app-log';
 import { readSyncFile, resolveSyncFileUri, writeSyncFile } from './storage-file';
@@ -306,6 +306,8 @@ const mobileSyncOrchestrator = createSyncOrchestrator<string | undefined, Mobile
       let cloudProvider: CloudProvider = CLOUD_PROVIDER_SELF_HOSTED;
       let dropboxClientId = '';
       let dropboxLastRev: string | null = null;
+      // ETag of the self-hosted copy from the last read; writes are conditional on it.
+      let cloudEtag: string | null = null;
       let fileSyncPath: string | null = null;
       let remoteDataForCompare: AppData | null = null;
       let webdavRemoteCorrupted = false;
@@ -445,11 +447,12 @@ const mobileSyncOrchestrator = createSyncOrchestrator<string | undefined, Mobile
           }
         }
         if (backend === 'cloud' && cloudConfig?.url) {
-          const data = await cloudGetJson<AppData>(cloudConfig.url, {
+          const { data, etag } = await cloudGetJsonWithEtag<AppData>(cloudConfig.url, {
             token: cloudConfig.token,
             timeoutMs: DEFAULT_SYNC_TIMEOUT_MS,
             fetcher: fetchWithAbort,
           });
+          cloudEtag = etag;
           remoteDataForCompare = data ?? null;
           return data;
         }
@@ -535,6 +538,7 @@ const mobileSyncOrchestrator = createSyncOrchestrator<string | undefined, Mobile
             token: cloudConfig.token,
             timeoutMs: DEFAULT_SYNC_TIMEOUT_MS,
             fetcher: fetchWithAbort,
+            ifMatch: cloudEtag,
           });
           remoteDataForCompare = sanitized;
           return;
@@ -1,5 +1,5 @@
 import { describe, expect, it } from 'vitest';
-import { buildAIConfig, buildCopilotConfig } from './ai-config';
+import { buildAIConfig, buildCopilotConfig, isBuiltInAIProvider } from './ai-config';
 import type { AppData } from './types';
 
 const createSettings = (ai: AppData['settings']['ai']): AppData['settings'] => ({
@@ -55,3 +55,27 @@ describe('ai-config endpoint mapping', () => {
         expect(config.endpoint).toBe('http://localhost:1234/v1/chat/completions');
     });
 });
+
+describe('ai-config registry providers', () => {
+    it('leaves the endpoint and default model to the registry entry', () => {
+        const config = buildAIConfig(
+            createSettings({
+                provider: 'ollama',
+                baseUrl: 'http://localhost:11434/v1',
+            }),
+            '',
+        );
+        expect(config).toEqual({ provider: 'ollama', apiKey: '', model: '' });
+    });
+
+    it('falls back to the main model for copilot', () => {
+        const config = buildCopilotConfig(createSettings({ provider: 'on-device', model: 'qwen2.5-1.5b' }), '');
+        expect(config.model).toBe('qwen2.5-1.5b');
+        expect(config.endpoint).toBeUndefined();
+    });
+
+    it('tells built-in providers from registry ids', () => {
+        expect(isBuiltInAIProvider('anthropic')).toBe(true);
+        expect(isBuiltInAIProvider('ollama')).toBe(false);
+    });
+});
@@ -1,10 +1,16 @@
 import type { AppData } from './types';
-import type { AIProviderConfig, AIProviderId } from './ai/types';
+import type { AIProviderConfig, AIProviderId, BuiltInAIProviderId } from './ai/types';
 import { DEFAULT_ANTHROPIC_THINKING_BUDGET, DEFAULT_GEMINI_THINKING_BUDGET, DEFAULT_REASONING_EFFORT, getDefaultAIConfig, getDefaultCopilotModel } from './ai/catalog';
+import { AI_PROVIDER_VALUE_SET } from './settings-options';
 
 const AI_KEY_PREFIX = 'mindwtr-ai-key';
 const OPENAI_CHAT_COMPLETIONS_PATH = '/chat/completions';
 
+/** Whether `provider` is one of the providers every app can call, rather than a desktop registry entry. */
+export function isBuiltInAIProvider(provider: AIProviderId): provider is BuiltInAIProviderId {
+    return AI_PROVIDER_VALUE_SET.has(provider);
+}
+
 export function getAIKeyStorageKey(provider: AIProviderId): string {
     return `${AI_KEY_PREFIX}:${provider}`;
 }
@@ -61,6 +67,10 @@ const resolveOpenAIEndpoint = (baseUrl?: string): string | undefined => {
 
 export function buildAIConfig(settings: AppData['settings'], apiKey: string): AIProviderConfig {
     const provider = (settings.ai?.provider ?? 'openai') as AIProviderId;
+    if (!isBuiltInAIProvider(provider)) {
+        // Registry providers carry their own endpoint; an empty model means their default model.
+        return { provider, apiKey, model: settings.ai?.model ?? '' };
+    }
     const defaults = getDefaultAIConfig(provider);
     const endpoint = provider === 'openai'
         ? resolveOpenAIEndpoint(settings.ai?.baseUrl)
@@ -77,6 +87,9 @@ export function buildAIConfig(settings: AppData['settings'], apiKey: string): AI
 
 export function buildCopilotConfig(settings: AppData['settings'], apiKey: string): AIProviderConfig {
     const provider = (settings.ai?.provider ?? 'openai') as AIProviderId;
+    if (!isBuiltInAIProvider(provider)) {
+        return { provider, apiKey, model: settings.ai?.copilotModel ?? settings.ai?.model ?? '' };
+    }
     const endpoint = provider === 'openai'
         ? resolveOpenAIEndpoint(settings.ai?.baseUrl)
         : undefined;
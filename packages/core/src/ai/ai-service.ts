@@ -2,8 +2,12 @@ import type { AIProvider, AIProviderConfig } from './types';
 import { createGeminiProvider } from './providers/gemini';
 import { createOpenAIProvider } from './providers/openai';
 import { createAnthropicProvider } from './providers/anthropic';
+import { createTransportProvider } from './providers/transport';
 
 export function createAIProvider(config: AIProviderConfig): AIProvider {
+    if (config.transport) {
+        return createTransportProvider(config, config.transport);
+    }
     switch (config.provider) {
         case 'gemini':
             return createGeminiProvider(config);
@@ -0,0 +1,63 @@
+import { describe, expect, it, vi } from 'vitest';
+import type { AIChatRequest } from '../types';
+import { createAIProvider } from '../ai-service';
+
+describe('transport provider', () => {
+    it('sends the prompt and config through the transport instead of fetch', async () => {
+        const transport = vi.fn(async (_request: AIChatRequest) => JSON.stringify({
+            question: 'What is the next action?',
+            options: [{ label: 'Do it', action: 'do' }],
+        }));
+        const controller = new AbortController();
+        const provider = createAIProvider({
+            provider: 'anthropic',
+            apiKey: '',
+            model: 'claude-sonnet-4-5',
+            timeoutMs: 15_000,
+            transport,
+        });
+
+        const result = await provider.clarifyTask({ title: 'Plan trip' }, { signal: controller.signal });
+
+        expect(result.question).toBe('What is the next action?');
+        expect(transport).toHaveBeenCalledTimes(1);
+        const request = transport.mock.calls[0]![0];
+        expect(request).toMatchObject({
+            provider: 'anthropic',
+            model: 'claude-sonnet-4-5',
+            timeoutMs: 15_000,
+            signal: controller.signal,
+        });
+        expect(request.system.length).toBeGreaterThan(0);
+        expect(request.user).toContain('Plan trip');
+    });
+
+    it('asks again for JSON when the first reply does not parse', async () => {
+        const transport = vi.fn(async (request: AIChatRequest) => (
+            request.user.includes('Return ONLY valid JSON')
+                ? JSON.stringify({ steps: ['Book flights'] })
+                : 'Sure! Here are some steps.'
+        ));
+        const provider = createAIProvider({ provider: 'gemini', apiKey: '', model: 'gemini-2.5-flash', transport });
+
+        const result = await provider.breakDownTask({ title: 'Plan trip' });
+
+        expect(result.steps).toEqual(['Book flights']);
+        expect(transport).toHaveBeenCalledTimes(2);
+    });
+
+    it('passes registry providers such as an on-device model through to the transport', async () => {
+        const transport = vi.fn(async (_request: AIChatRequest) => JSON.stringify({ steps: ['Pack bags'] }));
+        const provider = createAIProvider({ provider: 'local', apiKey: '', model: '', transport });
+
+        const result = await provider.breakDownTask({ title: 'Plan trip' });
+
+        expect(result.steps).toEqual(['Pack bags']);
+        // The backend picks the model from the registry entry (its default model or GGUF file).
+        expect(transport.mock.calls[0]![0]).toMatchObject({ provider: 'local', model: '' });
+    });
+
+    it('rejects registry providers without a transport', () => {
+        expect(() => createAIProvider({ provider: 'local', apiKey: '', model: '' })).toThrow('Unsupported AI provider');
+    });
+});
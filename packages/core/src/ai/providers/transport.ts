@@ -0,0 +1,52 @@
+import type { AIChatTransport, AIProvider, AIProviderConfig, AIRequestOptions, BreakdownInput, BreakdownResponse, ClarifyInput, ClarifyResponse, CopilotInput, CopilotResponse, ReviewAnalysisInput, ReviewAnalysisResponse } from '../types';
+import { buildBreakdownPrompt, buildClarifyPrompt, buildCopilotPrompt, buildReviewAnalysisPrompt } from '../prompts';
+import { normalizeTags, normalizeTimeEstimate, parseJson } from '../utils';
+import { isBreakdownResponse, isClarifyResponse, isCopilotResponse, isReviewAnalysisResponse } from '../validators';
+
+async function requestJson<T>(
+    config: AIProviderConfig,
+    transport: AIChatTransport,
+    prompt: { system: string; user: string },
+    validator: (value: unknown) => value is T,
+    options?: AIRequestOptions
+): Promise<T> {
+    const send = (user: string) => transport({
+        provider: config.provider,
+        model: config.model,
+        endpoint: config.endpoint,
+        system: prompt.system,
+        user,
+        timeoutMs: config.timeoutMs,
+        signal: options?.signal,
+    });
+    const text = await send(prompt.user);
+    try {
+        return parseJson<T>(text, validator);
+    } catch {
+        const retryText = await send(`${prompt.user}\n\nReturn ONLY valid JSON. Do not include any extra text.`);
+        return parseJson<T>(retryText, validator);
+    }
+}
+
+/** A provider whose chat requests go through `transport` instead of calling the API directly. */
+export function createTransportProvider(config: AIProviderConfig, transport: AIChatTransport): AIProvider {
+    return {
+        clarifyTask: (input: ClarifyInput, options?: AIRequestOptions): Promise<ClarifyResponse> =>
+            requestJson(config, transport, buildClarifyPrompt(input), isClarifyResponse, options),
+        breakDownTask: (input: BreakdownInput, options?: AIRequestOptions): Promise<BreakdownResponse> =>
+            requestJson(config, transport, buildBreakdownPrompt(input), isBreakdownResponse, options),
+        analyzeReview: (input: ReviewAnalysisInput, options?: AIRequestOptions): Promise<ReviewAnalysisResponse> =>
+            requestJson(config, transport, buildReviewAnalysisPrompt(input.items), isReviewAnalysisResponse, options),
+        predictMetadata: async (input: CopilotInput, options?: AIRequestOptions): Promise<CopilotResponse> => {
+            const parsed = await requestJson(config, transport, buildCopilotPrompt(input), isCopilotResponse, options);
+            const context = typeof parsed.context === 'string' ? parsed.context : undefined;
+            const timeEstimate = typeof parsed.timeEstimate === 'string' ? parsed.timeEstimate : undefined;
+            const tags = Array.isArray(parsed.tags) ? normalizeTags(parsed.tags) : [];
+            return {
+                context,
+                timeEstimate: normalizeTimeEstimate(timeEstimate) as CopilotResponse['timeEstimate'],
+                tags,
+            };
+        },
+    };
+}
@@ -1,6 +1,39 @@
 import type { TimeEstimate } from '../types';
 
-export type AIProviderId = 'gemini' | 'openai' | 'anthropic';
+/** Providers every app can call directly. */
+export type BuiltInAIProviderId = 'gemini' | 'openai' | 'anthropic';
+
+/**
+ * A built-in provider, or the id of one defined in the desktop provider registry (a custom
+ * endpoint or an on-device model). Registry providers are only reachable through the desktop backend.
+ */
+export type AIProviderId = BuiltInAIProviderId | (string & {});
+
+/** API dialect of a registry provider; `local` runs a GGUF model on the device. */
+export type AIProviderKind = 'openai-compatible' | 'anthropic' | 'gemini' | 'local';
+
+/** A provider in the desktop registry, as stored in config.toml. */
+export interface AIProviderDefinition {
+    id: string;
+    name: string;
+    kind: AIProviderKind;
+    /** API base URL, e.g. `http://localhost:11434/v1`; unused for `local`. */
+    baseUrl: string;
+    defaultModel?: string | null;
+    /** GGUF model file for `local` providers. */
+    modelPath?: string | null;
+    headers?: Record<string, string>;
+    /** Header that carries the API key verbatim instead of the kind's usual auth scheme. */
+    apiKeyHeader?: string | null;
+}
+
+/** A registry provider as the desktop backend reports it. */
+export interface AIProviderInfo extends AIProviderDefinition {
+    builtIn: boolean;
+    /** Defined or redefined in config.toml, as opposed to an untouched built-in. */
+    configured: boolean;
+    hasKey: boolean;
+}
 
 export type AIReasoningEffort = 'low' | 'medium' | 'high';
 
@@ -79,6 +112,19 @@ export interface BreakdownInput {
     projectTasks?: string[];
 }
 
+export interface AIChatRequest {
+    provider: AIProviderId;
+    model: string;
+    endpoint?: string;
+    system: string;
+    user: string;
+    timeoutMs?: number;
+    signal?: AbortSignal;
+}
+
+/** Sends one JSON-mode chat exchange and resolves with the reply text. */
+export type AIChatTransport = (request: AIChatRequest) => Promise<string>;
+
 export interface AIProviderConfig {
     provider: AIProviderId;
     apiKey: string;
@@ -87,6 +133,8 @@ export interface AIProviderConfig {
     reasoningEffort?: AIReasoningEffort;
     thinkingBudget?: number;
     timeoutMs?: number;
+    /** Replaces the provider's own HTTP calls, e.g. with a backend that holds the API key. */
+    transport?: AIChatTransport;
 }
 
 export interface AIRequestOptions {
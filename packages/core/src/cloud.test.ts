@@ -1,5 +1,6 @@
 import { describe, expect, it, vi } from 'vitest';
-import { cloudDeleteFile, cloudGetJson, cloudPutJson } from './cloud';
+import { cloudDeleteFile, cloudGetJson, cloudGetJsonWithEtag, cloudPutJson } from './cloud';
+import { SyncConflictError } from './sync';
 
 const okResponse = (text: string) =>
     ({
@@ -47,6 +48,30 @@ describe('cloud sync http helpers', () => {
         expect((init.headers as Record<string, string>)['Content-Type']).toBe('application/json');
     });
 
+    it('reads the ETag and writes conditionally on it', async () => {
+        const fetcher = vi.fn(async () => ({
+            ...okResponse(JSON.stringify({ tasks: [] })),
+            headers: new Headers({ ETag: '"v1"' }),
+        }) as unknown as Response);
+        const { data, etag } = await cloudGetJsonWithEtag('https://example.com/v1/data', { fetcher });
+        expect(data).toEqual({ tasks: [] });
+        expect(etag).toBe('"v1"');
+
+        const put = vi.fn(async () => okResponse(''));
+        await cloudPutJson('https://example.com/v1/data', {}, { fetcher: put, ifMatch: etag });
+        await cloudPutJson('https://example.com/v1/data', {}, { fetcher: put, ifMatch: null });
+        const headers = put.mock.calls.map((call) => (call as unknown as [string, RequestInit])[1].headers as Record<string, string>);
+        expect(headers[0]['If-Match']).toBe('"v1"');
+        expect(headers[1]['If-None-Match']).toBe('*');
+        expect(headers[1]['If-Match']).toBeUndefined();
+    });
+
+    it('reports a stale write as a sync conflict', async () => {
+        const fetcher = vi.fn(async () => errorResponse(412, 'Precondition Failed'));
+        await expect(cloudPutJson('https://example.com/v1/data', {}, { fetcher, ifMatch: '"v0"' }))
+            .rejects.toBeInstanceOf(SyncConflictError);
+    });
+
     it('treats 404 delete as success', async () => {
         const fetcher = vi.fn(async () => errorResponse(404, 'Not Found'));
         await expect(cloudDeleteFile('https://example.com/v1/file', { fetcher })).resolves.toBeUndefined();
@@ -7,6 +7,7 @@ import {
     toArrayBuffer,
     toUint8Array,
 } from './http-utils';
+import { SyncConflictError } from './sync';
 
 export interface CloudOptions {
     token?: string;
@@ -14,6 +15,11 @@ export interface CloudOptions {
     timeoutMs?: number;
     fetcher?: typeof fetch;
     onProgress?: (loaded: number, total: number) => void;
+    /**
+     * For data PUTs: the ETag of the version being replaced (sent as If-Match), or null when the
+     * server had none yet (sent as If-None-Match: *).
+     */
+    ifMatch?: string | null;
 }
 
 function buildHeaders(options: CloudOptions): Record<string, string> {
@@ -32,6 +38,14 @@ export async function cloudGetJson<T>(
     url: string,
     options: CloudOptions = {},
 ): Promise<T | null> {
+    return (await cloudGetJsonWithEtag<T>(url, options)).data;
+}
+
+/** Like `cloudGetJson`, plus the ETag to pass as `ifMatch` when writing the data back. */
+export async function cloudGetJsonWithEtag<T>(
+    url: string,
+    options: CloudOptions = {},
+): Promise<{ data: T | null; etag: string | null }> {
     assertSecureUrl(url, CLOUD_HTTPS_ERROR, CLOUD_INSECURE_OPTIONS);
     const fetcher = options.fetcher ?? fetch;
     const res = await fetchWithTimeout(
@@ -45,14 +59,15 @@ export async function cloudGetJson<T>(
         CLOUD_TIMEOUT_ERROR,
     );
 
-    if (res.status === 404) return null;
+    if (res.status === 404) return { data: null, etag: null };
     if (!res.ok) {
         throw new Error(`Cloud GET failed (${res.status}): ${res.statusText}`);
     }
 
+    const etag = res.headers?.get('ETag') ?? null;
     const text = await res.text();
     try {
-        return JSON.parse(text) as T;
+        return { data: JSON.parse(text) as T, etag };
     } catch (error) {
         throw new Error(`Cloud GET failed: invalid JSON (${(error as Error).message})`);
     }
@@ -67,6 +82,11 @@ export async function cloudPutJson(
     const fetcher = options.fetcher ?? fetch;
     const headers = buildHeaders(options);
     headers['Content-Type'] = headers['Content-Type'] || 'application/json';
+    if (typeof options.ifMatch === 'string') {
+        headers['If-Match'] = options.ifMatch;
+    } else if (options.ifMatch === null) {
+        headers['If-None-Match'] = '*';
+    }
 
     const res = await fetchWithTimeout(
         url,
@@ -80,6 +100,9 @@ export async function cloudPutJson(
         CLOUD_TIMEOUT_ERROR,
     );
 
+    if (res.status === 412) {
+        throw new SyncConflictError('Cloud data changed since it was read');
+    }
     if (!res.ok) {
         throw new Error(`Cloud PUT failed (${res.status}): ${res.statusText}`);
     }
@@ -1,5 +1,5 @@
 import { SUPPORTED_LANGUAGES } from './i18n/i18n-constants';
-import type { AIProviderId, AIReasoningEffort } from './ai/types';
+import type { AIProviderId, AIReasoningEffort, BuiltInAIProviderId } from './ai/types';
 import type { AppData } from './types';
 
 type ThemeValue = NonNullable<AppData['settings']['theme']>;
@@ -39,7 +39,7 @@ const DENSITY_VALUE_FLAGS: Record<DensityValue, true> = {
     compact: true,
 };
 
-const AI_PROVIDER_VALUE_FLAGS: Record<AIProviderId, true> = {
+const AI_PROVIDER_VALUE_FLAGS: Record<BuiltInAIProviderId, true> = {
     gemini: true,
     openai: true,
     anthropic: true,
@@ -83,9 +83,15 @@ export const SETTINGS_KEYBINDING_STYLE_VALUE_SET = new Set<KeybindingStyleValue>
 export const SETTINGS_DENSITY_VALUES = Object.keys(DENSITY_VALUE_FLAGS) as DensityValue[];
 export const SETTINGS_DENSITY_VALUE_SET = new Set<DensityValue>(SETTINGS_DENSITY_VALUES);
 
-export const AI_PROVIDER_VALUES = Object.keys(AI_PROVIDER_VALUE_FLAGS) as AIProviderId[];
+export const AI_PROVIDER_VALUES = Object.keys(AI_PROVIDER_VALUE_FLAGS) as BuiltInAIProviderId[];
 export const AI_PROVIDER_VALUE_SET = new Set<AIProviderId>(AI_PROVIDER_VALUES);
 
+/** Ids the desktop provider registry accepts for custom providers. */
+const AI_PROVIDER_ID_PATTERN = /^[a-z0-9_-]{1,64}$/;
+
+export const isValidAIProviderId = (provider: unknown): provider is AIProviderId =>
+    typeof provider === 'string' && AI_PROVIDER_ID_PATTERN.test(provider);
+
 export const AI_REASONING_EFFORT_VALUES = Object.keys(AI_REASONING_EFFORT_VALUE_FLAGS) as AIReasoningEffort[];
 export const AI_REASONING_EFFORT_VALUE_SET = new Set<AIReasoningEffort>(AI_REASONING_EFFORT_VALUES);
 
@@ -1,11 +1,14 @@
 import { describe, expect, it } from 'vitest';
-import { getFileSyncDir, isSyncFilePath, normalizeSyncBackend } from './sync-service-utils';
+import { getFileSyncDir, isEntitySyncFilePath, isSyncFilePath, normalizeSyncBackend } from './sync-service-utils';
 
 describe('sync-service-utils', () => {
     it('normalizes sync backend values', () => {
         expect(normalizeSyncBackend('file')).toBe('file');
         expect(normalizeSyncBackend('webdav')).toBe('webdav');
         expect(normalizeSyncBackend('cloud')).toBe('cloud');
+        expect(normalizeSyncBackend('s3')).toBe('s3');
+        expect(normalizeSyncBackend('git')).toBe('git');
+        expect(normalizeSyncBackend('sftp')).toBe('sftp');
         expect(normalizeSyncBackend('off')).toBe('off');
         expect(normalizeSyncBackend('invalid')).toBe('off');
         expect(normalizeSyncBackend(null)).toBe('off');
@@ -17,6 +20,15 @@ describe('sync-service-utils', () => {
         expect(isSyncFilePath('/storage/other.json')).toBe(false);
     });
 
+    it('detects per-entity sync layout files', () => {
+        expect(isEntitySyncFilePath('/storage/manifest.json')).toBe(true);
+        expect(isEntitySyncFilePath('C:\\Sync\\tasks\\t1.json')).toBe(true);
+        expect(isEntitySyncFilePath('/storage/projects/p1.json')).toBe(true);
+        expect(isEntitySyncFilePath('/storage/tasks/t1.json.tmp')).toBe(false);
+        expect(isEntitySyncFilePath('/storage/attachments/a1.json')).toBe(false);
+        expect(isEntitySyncFilePath('/storage/data.json')).toBe(false);
+    });
+
     it('resolves file sync base directory from file or folder paths', () => {
         expect(getFileSyncDir('/storage/folder/data.json')).toBe('/storage/folder');
         expect(getFileSyncDir('/storage/folder/mindwtr-sync.json')).toBe('/storage/folder');
@@ -1,7 +1,9 @@
-export type SyncBackend = 'off' | 'file' | 'webdav' | 'cloud';
+export type SyncBackend = 'off' | 'file' | 'webdav' | 'cloud' | 's3' | 'git' | 'sftp';
 
 const DEFAULT_SYNC_FILE_NAME = 'data.json';
 const DEFAULT_LEGACY_SYNC_FILE_NAME = 'mindwtr-sync.json';
+const SYNC_MANIFEST_FILE_NAME = 'manifest.json';
+const SYNC_ENTITY_COLLECTIONS = ['tasks', 'projects', 'sections', 'areas'];
 
 export const normalizePath = (input: string): string => input.replace(/\\/g, '/').toLowerCase();
 
@@ -14,8 +16,17 @@ export const isSyncFilePath = (
     return normalized.endsWith(`/${syncFileName}`) || normalized.endsWith(`/${legacySyncFileName}`);
 };
 
+// Files of the per-entity sync layout: the manifest and one JSON file per item.
+export const isEntitySyncFilePath = (path: string): boolean => {
+    const segments = normalizePath(path).split('/');
+    const name = segments[segments.length - 1] ?? '';
+    if (name === SYNC_MANIFEST_FILE_NAME) return true;
+    const parent = segments[segments.length - 2] ?? '';
+    return SYNC_ENTITY_COLLECTIONS.includes(parent) && name.endsWith('.json');
+};
+
 export const normalizeSyncBackend = (raw: string | null): SyncBackend => {
-    if (raw === 'off' || raw === 'file' || raw === 'webdav' || raw === 'cloud') return raw;
+    if (raw === 'off' || raw === 'file' || raw === 'webdav' || raw === 'cloud' || raw === 's3' || raw === 'git' || raw === 'sftp') return raw;
     return 'off';
 };
 
@@ -1,5 +1,5 @@
 import { describe, it, expect, vi } from 'vitest';
-import { CLOCK_SKEW_THRESHOLD_MS, mergeAppData, mergeAppDataWithStats, filterDeleted, appendSyncHistory, performSyncCycle } from './sync';
+import { CLOCK_SKEW_THRESHOLD_MS, MAX_SYNC_CONFLICT_RETRIES, mergeAppData, mergeAppDataWithStats, filterDeleted, appendSyncHistory, performSyncCycle, summarizeSyncChanges, previewSyncMerge, SyncConflictError, isEncryptedSyncPayload, isSyncLayoutMarker, withSyncIntegrity } from './sync';
 import { AppData, Task, Project, Attachment, Section, Area } from './types';
 
 describe('Sync Logic', () => {
@@ -1179,6 +1179,73 @@ describe('Sync Logic', () => {
             expect(result.stats.sections.conflicts).toBe(0);
         });
 
+        it('merges through mergeData when one is given', async () => {
+            const local = mockAppData([createMockTask('local-only', '2024-01-02T00:00:00.000Z')]);
+            const mergeData = vi.fn(async (localData: AppData, remoteData: AppData | null) => ({
+                data: localData,
+                stats: mergeAppDataWithStats(localData, remoteData ?? mockAppData()).stats,
+            }));
+            let remoteWrite: AppData | null = null;
+
+            const result = await performSyncCycle({
+                readLocal: async () => local,
+                readRemote: async () => null,
+                writeLocal: async () => undefined,
+                writeRemote: async (data) => {
+                    remoteWrite = data;
+                },
+                mergeData,
+            });
+
+            expect(mergeData).toHaveBeenCalledTimes(1);
+            expect(mergeData.mock.calls[0][1]).toBeNull();
+            expect(result.data.tasks.map((task) => task.id)).toEqual(['local-only']);
+            expect(remoteWrite).not.toBeNull();
+        });
+
+        it('pulls and merges again when the remote changed before the write', async () => {
+            const local = mockAppData([createMockTask('local-only', '2024-01-02T00:00:00.000Z')]);
+            const remoteVersions = [
+                mockAppData([createMockTask('remote-1', '2024-01-02T00:00:00.000Z')]),
+                mockAppData([
+                    createMockTask('remote-1', '2024-01-02T00:00:00.000Z'),
+                    createMockTask('remote-2', '2024-01-03T00:00:00.000Z'),
+                ]),
+            ];
+            let reads = 0;
+            const remoteWrites: AppData[] = [];
+
+            const result = await performSyncCycle({
+                readLocal: async () => local,
+                readRemote: async () => remoteVersions[Math.min(reads++, remoteVersions.length - 1)],
+                writeLocal: async () => undefined,
+                writeRemote: async (data) => {
+                    remoteWrites.push(data);
+                    if (remoteWrites.length === 1) {
+                        throw new SyncConflictError();
+                    }
+                },
+            });
+
+            expect(reads).toBe(2);
+            expect(remoteWrites).toHaveLength(2);
+            expect(result.data.tasks.map((task) => task.id).sort()).toEqual(['local-only', 'remote-1', 'remote-2']);
+        });
+
+        it('gives up after repeated remote conflicts', async () => {
+            const writeRemote = vi.fn(async () => {
+                throw new SyncConflictError('Remote kept changing');
+            });
+
+            await expect(performSyncCycle({
+                readLocal: async () => mockAppData(),
+                readRemote: async () => mockAppData(),
+                writeLocal: async () => undefined,
+                writeRemote,
+            })).rejects.toThrow('Remote kept changing');
+            expect(writeRemote).toHaveBeenCalledTimes(MAX_SYNC_CONFLICT_RETRIES + 1);
+        });
+
         it('fails before writes when merged data is invalid', async () => {
             let wroteLocal = false;
             let wroteRemote = false;
@@ -1239,6 +1306,51 @@ describe('Sync Logic', () => {
             expect(wroteRemote).toBe(false);
         });
 
+        it('refuses to merge over end-to-end encrypted remote data', async () => {
+            const writeRemote = vi.fn(async () => undefined);
+            const writeLocal = vi.fn(async () => undefined);
+            const envelope = { mindwtrEncrypted: 1, keyId: 'abc', kdf: {}, nonce: 'n', ciphertext: 'c' };
+
+            await expect(performSyncCycle({
+                readLocal: async () => mockAppData(),
+                readRemote: async () => envelope as unknown as AppData,
+                writeLocal,
+                writeRemote,
+            })).rejects.toThrow('end-to-end encrypted');
+            expect(writeLocal).not.toHaveBeenCalled();
+            expect(writeRemote).not.toHaveBeenCalled();
+            expect(isEncryptedSyncPayload(envelope)).toBe(true);
+            expect(isEncryptedSyncPayload(mockAppData())).toBe(false);
+        });
+
+        it('adds the integrity block desktop requires, replacing a stale checksum', () => {
+            const data = {
+                ...mockAppData([createMockTask('t1', '2024-01-01T00:00:00.000Z'), createMockTask('t2', '2024-01-01T00:00:00.000Z')]),
+                syncIntegrity: { schemaVersion: 1, counts: { tasks: 5, projects: 0, sections: 0, areas: 0 }, checksum: 'sha256:old' },
+            } as AppData;
+
+            const written = withSyncIntegrity(data);
+
+            expect(written.syncIntegrity).toEqual({ schemaVersion: 1, counts: { tasks: 2, projects: 0, sections: 0, areas: 0 } });
+            expect(written.tasks).toBe(data.tasks);
+        });
+
+        it('refuses to sync against the per-entity layout marker', async () => {
+            const writeRemote = vi.fn(async () => undefined);
+            const notice = 'This sync folder stores one file per item.';
+            const marker = { mindwtrSyncLayout: 'entities', version: 1, tasks: notice, projects: notice, sections: notice, areas: notice, settings: notice };
+
+            await expect(performSyncCycle({
+                readLocal: async () => mockAppData(),
+                readRemote: async () => marker as unknown as AppData,
+                writeLocal: async () => undefined,
+                writeRemote,
+            })).rejects.toThrow('one file per item');
+            expect(writeRemote).not.toHaveBeenCalled();
+            expect(isSyncLayoutMarker(marker)).toBe(true);
+            expect(isSyncLayoutMarker(mockAppData())).toBe(false);
+        });
+
         it('drops empty task revBy values from incoming payloads', async () => {
             let saved: AppData | null = null;
             const incoming = mockAppData([
@@ -1590,6 +1702,105 @@ describe('Sync Logic', () => {
         });
     });
 
+    describe('summarizeSyncChanges', () => {
+        it('counts added, updated and deleted entities across collections', () => {
+            const before = mockAppData(
+                [
+                    createMockTask('kept', '2024-01-01T00:00:00.000Z'),
+                    createMockTask('edited', '2024-01-01T00:00:00.000Z'),
+                    createMockTask('tombstoned', '2024-01-01T00:00:00.000Z'),
+                    createMockTask('purged', '2024-01-01T00:00:00.000Z'),
+                ],
+                [createMockProject('p1', '2024-01-01T00:00:00.000Z')]
+            );
+            const after = mockAppData(
+                [
+                    createMockTask('kept', '2024-01-01T00:00:00.000Z'),
+                    createMockTask('edited', '2024-01-02T00:00:00.000Z'),
+                    createMockTask('tombstoned', '2024-01-02T00:00:00.000Z', '2024-01-02T00:00:00.000Z'),
+                    createMockTask('new', '2024-01-02T00:00:00.000Z'),
+                    createMockTask('remote-tombstone', '2024-01-02T00:00:00.000Z', '2024-01-02T00:00:00.000Z'),
+                ],
+                [createMockProject('p1', '2024-01-01T00:00:00.000Z'), createMockProject('p2', '2024-01-02T00:00:00.000Z')]
+            );
+
+            expect(summarizeSyncChanges(before, after)).toEqual({ added: 2, updated: 1, deleted: 2 });
+        });
+    });
+
+    describe('previewSyncMerge', () => {
+        const now = () => '2024-01-05T00:00:00.000Z';
+        const ids = (items: Array<{ id: string }>) => items.map((item) => item.id);
+
+        it('reports the changes for each side without touching the inputs', async () => {
+            const local = mockAppData([
+                createMockTask('same', '2024-01-01T00:00:00.000Z'),
+                createMockTask('edited-remotely', '2024-01-01T00:00:00.000Z'),
+                createMockTask('local-only', '2024-01-02T00:00:00.000Z'),
+                createMockTask('deleted-remotely', '2024-01-01T00:00:00.000Z'),
+            ]);
+            const remote = mockAppData([
+                createMockTask('same', '2024-01-01T00:00:00.000Z'),
+                { ...createMockTask('edited-remotely', '2024-01-03T00:00:00.000Z'), title: 'Renamed' },
+                createMockTask('remote-only', '2024-01-02T00:00:00.000Z'),
+                createMockTask('deleted-remotely', '2024-01-02T00:00:00.000Z', '2024-01-02T00:00:00.000Z'),
+            ]);
+            const localSnapshot = JSON.stringify(local);
+            const remoteSnapshot = JSON.stringify(remote);
+
+            const preview = await previewSyncMerge(local, remote, { now });
+
+            expect(ids(preview.local.created)).toEqual(['remote-only']);
+            expect(preview.local.updated).toEqual([{ entity: 'task', id: 'edited-remotely', title: 'Renamed' }]);
+            expect(ids(preview.local.deleted)).toEqual(['deleted-remotely']);
+            expect(ids(preview.remote.created)).toEqual(['local-only']);
+            expect(preview.remote.updated).toEqual([]);
+            expect(preview.remote.deleted).toEqual([]);
+            expect(ids(preview.conflicts)).toEqual(['edited-remotely', 'deleted-remotely']);
+            expect(preview.conflictCount).toBe(2);
+            expect(JSON.stringify(local)).toBe(localSnapshot);
+            expect(JSON.stringify(remote)).toBe(remoteSnapshot);
+        });
+
+        it('treats a missing remote as a first push', async () => {
+            const local = mockAppData([createMockTask('t1', '2024-01-01T00:00:00.000Z')], [createMockProject('p1', '2024-01-01T00:00:00.000Z')]);
+
+            const preview = await previewSyncMerge(local, null, { now });
+
+            expect(preview.local).toEqual({ created: [], updated: [], deleted: [] });
+            expect(preview.remote.created).toEqual([
+                { entity: 'task', id: 't1', title: 'Task t1' },
+                { entity: 'project', id: 'p1', title: 'Project p1' },
+            ]);
+            expect(preview.conflictCount).toBe(0);
+        });
+
+        it('reports what the given merge decided instead of the built-in merge', async () => {
+            const local = mockAppData([createMockTask('kept', '2024-01-01T00:00:00.000Z'), createMockTask('purged', '2024-01-01T00:00:00.000Z')]);
+            const remote = mockAppData([createMockTask('kept', '2024-01-01T00:00:00.000Z')]);
+            // A base-aware merge drops the item the remote purged; the built-in merge would push it back.
+            const purgedTombstone = createMockTask('purged', '2024-01-02T00:00:00.000Z', '2024-01-02T00:00:00.000Z');
+            const mergeData = vi.fn(async (localData: AppData, remoteData: AppData | null) => (
+                mergeAppDataWithStats(localData, { ...remoteData!, tasks: [...remoteData!.tasks, purgedTombstone] })
+            ));
+
+            const preview = await previewSyncMerge(local, remote, { now, mergeData });
+
+            expect(mergeData).toHaveBeenCalledTimes(1);
+            expect(mergeData.mock.calls[0]![1]!.tasks.map((task) => task.id)).toEqual(['kept']);
+            expect(ids(preview.local.deleted)).toEqual(['purged']);
+            expect(preview.remote.created).toEqual([]);
+        });
+
+        it('passes a null remote to the given merge when there is no remote yet', async () => {
+            const mergeData = vi.fn(async (localData: AppData) => mergeAppDataWithStats(localData, localData));
+
+            await previewSyncMerge(mockAppData([createMockTask('t1', '2024-01-01T00:00:00.000Z')]), null, { now, mergeData });
+
+            expect(mergeData.mock.calls[0]![1]).toBeNull();
+        });
+    });
+
     describe('filterDeleted', () => {
         it('should filter out items with deletedAt set', () => {
             const tasks = [
@@ -3,7 +3,6 @@ import type { AppData, Attachment, Project, Task, Area, SettingsSyncGroup } from
 import { normalizeTaskForLoad } from './task-status';
 import { logWarn } from './logger';
 import {
-    AI_PROVIDER_VALUE_SET,
     AI_REASONING_EFFORT_VALUE_SET,
     SETTINGS_DENSITY_VALUE_SET,
     SETTINGS_KEYBINDING_STYLE_VALUE_SET,
@@ -13,6 +12,7 @@ import {
     STT_FIELD_STRATEGY_VALUE_SET,
     STT_MODE_VALUE_SET,
     STT_PROVIDER_VALUE_SET,
+    isValidAIProviderId,
 } from './settings-options';
 
 export interface EntityMergeStats {
@@ -70,6 +70,11 @@ export type SyncCycleIO = {
     readRemote: () => Promise<AppData | null | undefined>;
     writeLocal: (data: AppData) => Promise<void>;
     writeRemote: (data: AppData) => Promise<void>;
+    /**
+     * Replaces the built-in merge, e.g. with one that also knows the last synced state.
+     * `remote` is null when there is no remote data yet.
+     */
+    mergeData?: (local: AppData, remote: AppData | null) => Promise<MergeResult>;
     historyContext?: {
         backend?: SyncHistoryEntry['backend'];
         type?: SyncHistoryEntry['type'];
@@ -80,6 +85,20 @@ export type SyncCycleIO = {
     onStep?: (step: SyncStep) => void;
 };
 
+/** How often `performSyncCycle` pulls and merges again when the remote changed under its write. */
+export const MAX_SYNC_CONFLICT_RETRIES = 3;
+
+/**
+ * Thrown by `SyncCycleIO.writeRemote` when the remote is no longer the version that was read,
+ * so the cycle can pull and merge again instead of overwriting it.
+ */
+export class SyncConflictError extends Error {
+    constructor(message = 'Remote data changed during sync') {
+        super(message);
+        this.name = 'SyncConflictError';
+    }
+}
+
 export type SyncCycleResult = {
     data: AppData;
     stats: MergeStats;
@@ -516,7 +535,7 @@ const sanitizeAiSettings = (
     if (next.enabled !== undefined && typeof next.enabled !== 'boolean') {
         next.enabled = fallback?.enabled;
     }
-    if (next.provider !== undefined && !AI_PROVIDER_VALUE_SET.has(next.provider)) {
+    if (next.provider !== undefined && !isValidAIProviderId(next.provider)) {
         next.provider = fallback?.provider;
     }
     if (next.baseUrl !== undefined && !isNonEmptyString(next.baseUrl)) {
@@ -605,7 +624,8 @@ const sanitizeMergedSettingsForSync = (
     return next;
 };
 
-const mergeSettingsForSync = (localSettings: AppData['settings'], incomingSettings: AppData['settings']): AppData['settings'] => {
+/** Merge the synced settings group by group; within a group, the side edited last wins. */
+export const mergeSettingsForSync = (localSettings: AppData['settings'], incomingSettings: AppData['settings']): AppData['settings'] => {
     const merged: AppData['settings'] = { ...localSettings };
     const nextSyncUpdatedAt: NonNullable<AppData['settings']['syncPreferencesUpdatedAt']> = {
         ...(localSettings.syncPreferencesUpdatedAt ?? {}),
@@ -1158,6 +1178,94 @@ export function mergeAppData(local: AppData, incoming: AppData): AppData {
     return mergeAppDataWithStats(local, incoming).data;
 }
 
+export type SyncChangeSummary = {
+    added: number;
+    updated: number;
+    deleted: number;
+};
+
+type SyncChangeItem = { id: string; updatedAt?: string; deletedAt?: string; rev?: number; title?: string; name?: string };
+
+export type SyncPreviewEntity = 'task' | 'project' | 'section' | 'area';
+
+export type SyncPreviewItem = {
+    entity: SyncPreviewEntity;
+    id: string;
+    title: string;
+};
+
+export type SyncPreviewChanges = {
+    created: SyncPreviewItem[];
+    updated: SyncPreviewItem[];
+    deleted: SyncPreviewItem[];
+};
+
+export type SyncPreview = {
+    /** Changes the sync would apply to this device. */
+    local: SyncPreviewChanges;
+    /** Changes the sync would write to the remote. */
+    remote: SyncPreviewChanges;
+    /** Entities edited on both sides; capped like the merge stats, see `conflictCount` for the total. */
+    conflicts: SyncPreviewItem[];
+    conflictCount: number;
+};
+
+const SYNC_CHANGE_COLLECTIONS = [
+    ['task', 'tasks'],
+    ['project', 'projects'],
+    ['section', 'sections'],
+    ['area', 'areas'],
+] as const satisfies ReadonlyArray<readonly [SyncPreviewEntity, keyof AppData]>;
+
+const toSyncPreviewItem = (entity: SyncPreviewEntity, item: SyncChangeItem): SyncPreviewItem => ({
+    entity,
+    id: item.id,
+    title: item.title ?? item.name ?? '',
+});
+
+/**
+ * List the entities created, updated and deleted between two snapshots.
+ * Tombstoned or purged items count as deleted; revision or timestamp bumps count as updated.
+ */
+function collectSyncChanges(before: AppData, after: AppData): SyncPreviewChanges {
+    const changes: SyncPreviewChanges = { created: [], updated: [], deleted: [] };
+    for (const [entity, key] of SYNC_CHANGE_COLLECTIONS) {
+        const beforeItems = (before[key] || []) as SyncChangeItem[];
+        const afterItems = (after[key] || []) as SyncChangeItem[];
+        const previousById = new Map(beforeItems.map((item) => [item.id, item]));
+        const nextIds = new Set<string>();
+        for (const item of afterItems) {
+            nextIds.add(item.id);
+            const previous = previousById.get(item.id);
+            if (!previous) {
+                if (!item.deletedAt) changes.created.push(toSyncPreviewItem(entity, item));
+                continue;
+            }
+            if (item.deletedAt && !previous.deletedAt) {
+                changes.deleted.push(toSyncPreviewItem(entity, previous));
+            } else if (item.rev !== previous.rev || item.updatedAt !== previous.updatedAt) {
+                changes.updated.push(toSyncPreviewItem(entity, item));
+            }
+        }
+        for (const [id, previous] of previousById) {
+            if (!nextIds.has(id) && !previous.deletedAt) changes.deleted.push(toSyncPreviewItem(entity, previous));
+        }
+    }
+    return changes;
+}
+
+/**
+ * Count how a sync changed the local entities, for the sync journal.
+ */
+export function summarizeSyncChanges(before: AppData, after: AppData): SyncChangeSummary {
+    const changes = collectSyncChanges(before, after);
+    return {
+        added: changes.created.length,
+        updated: changes.updated.length,
+        deleted: changes.deleted.length,
+    };
+}
+
 const withPendingRemoteWriteFlag = (data: AppData, pendingAt: string): AppData => ({
     ...data,
     settings: {
@@ -1179,16 +1287,124 @@ const clearPendingRemoteWriteFlag = (data: AppData): AppData => {
 
 const hasPendingRemoteWriteFlag = (data: AppData): boolean => isValidTimestamp(data.settings.pendingRemoteWriteAt);
 
+const EMPTY_SYNC_PAYLOAD: AppData = { tasks: [], projects: [], sections: [], areas: [], settings: {} };
+
+/**
+ * Sync data the desktop app encrypted end to end. It has none of the payload fields, so a client
+ * without the key would merge it as empty and overwrite the ciphertext with its own data.
+ */
+export const isEncryptedSyncPayload = (data: unknown): boolean =>
+    isObjectRecord(data) && typeof data.mindwtrEncrypted === 'number';
+
+const SYNC_SCHEMA_VERSION = 1;
+
+export type SyncIntegrity = {
+    schemaVersion: number;
+    counts: Record<'tasks' | 'projects' | 'sections' | 'areas', number>;
+    checksum?: string;
+};
+
+/**
+ * Add the integrity block desktop requires on a file-sync `data.json`, so a truncated file cannot
+ * pass as a smaller dataset. Desktop also stores a checksum; other writers may leave it out.
+ */
+export function withSyncIntegrity(data: AppData): AppData & { syncIntegrity: SyncIntegrity } {
+    return {
+        ...data,
+        syncIntegrity: {
+            schemaVersion: SYNC_SCHEMA_VERSION,
+            counts: {
+                tasks: data.tasks?.length ?? 0,
+                projects: data.projects?.length ?? 0,
+                sections: data.sections?.length ?? 0,
+                areas: data.areas?.length ?? 0,
+            },
+        },
+    };
+}
+
+/**
+ * The `data.json` desktop leaves in a sync folder it switched to one file per item. Its lists are
+ * not arrays, so clients that only know `data.json` refuse it instead of syncing a second copy there.
+ */
+export const isSyncLayoutMarker = (data: unknown): boolean =>
+    isObjectRecord(data) && typeof data.mindwtrSyncLayout === 'string';
+
+const assertSyncPayloadShape = (data: unknown, source: 'local' | 'remote'): void => {
+    if (isEncryptedSyncPayload(data)) {
+        throw new Error(`The ${source} sync data is end-to-end encrypted. Only the desktop app can sync it, using the encryption passphrase.`);
+    }
+    if (isSyncLayoutMarker(data)) {
+        throw new Error('The sync folder stores one file per item. Only the desktop app can sync it, or switch it back to a single data.json on desktop.');
+    }
+    const shapeErrors = validateSyncPayloadShape(data, source);
+    if (shapeErrors.length === 0) return;
+    const sample = shapeErrors.slice(0, 3).join('; ');
+    if (source === 'remote') {
+        logWarn('Invalid remote sync payload shape', {
+            scope: 'sync',
+            context: {
+                issues: shapeErrors.length,
+                sample,
+            },
+        });
+    }
+    throw new Error(`Invalid ${source} sync payload: ${sample}`);
+};
+
+/**
+ * Dry run of the merge step of `performSyncCycle`: what a sync would change on each side,
+ * computed from the two payloads without writing anything.
+ */
+export async function previewSyncMerge(
+    localDataRaw: AppData,
+    remoteDataRaw: AppData | null | undefined,
+    options: Pick<SyncCycleIO, 'mergeData' | 'tombstoneRetentionDays' | 'now'> = {}
+): Promise<SyncPreview> {
+    const nowIso = options.now ? options.now() : new Date().toISOString();
+    assertSyncPayloadShape(localDataRaw, 'local');
+    if (remoteDataRaw) {
+        assertSyncPayloadShape(remoteDataRaw, 'remote');
+    }
+    const localData = purgeExpiredTombstones(normalizeAppData(localDataRaw), nowIso, options.tombstoneRetentionDays).data;
+    const remoteData = purgeExpiredTombstones(
+        normalizeAppData(remoteDataRaw || EMPTY_SYNC_PAYLOAD),
+        nowIso,
+        options.tombstoneRetentionDays
+    ).data;
+    // An interrupted sync pushes local data before reading the remote, so the merge sees local on both sides.
+    const mergeInput = hasPendingRemoteWriteFlag(localData) ? localData : remoteDataRaw ? remoteData : null;
+    // Same merge the sync would run, so a base-aware merge can report purges and stale tombstones too.
+    const mergeResult = options.mergeData
+        ? await options.mergeData(localData, mergeInput)
+        : mergeAppDataWithStats(localData, mergeInput ?? remoteData);
+    const mergedData = purgeExpiredTombstones(mergeResult.data, nowIso, options.tombstoneRetentionDays).data;
+
+    const conflicts: SyncPreviewItem[] = [];
+    let conflictCount = 0;
+    for (const [entity, key] of SYNC_CHANGE_COLLECTIONS) {
+        const stats = mergeResult.stats[key];
+        conflictCount += stats.conflicts || 0;
+        const mergedById = new Map(((mergedData[key] || []) as SyncChangeItem[]).map((item) => [item.id, item]));
+        for (const id of stats.conflictIds || []) {
+            conflicts.push(toSyncPreviewItem(entity, mergedById.get(id) ?? { id }));
+        }
+    }
+
+    return {
+        local: collectSyncChanges(localData, mergedData),
+        remote: collectSyncChanges(remoteData, mergedData),
+        conflicts,
+        conflictCount,
+    };
+}
+
 export async function performSyncCycle(io: SyncCycleIO): Promise<SyncCycleResult> {
     const nowIso = io.now ? io.now() : new Date().toISOString();
 
     io.onStep?.('read-local');
     const localDataRaw = await io.readLocal();
-    const localShapeErrors = validateSyncPayloadShape(localDataRaw, 'local');
-    if (localShapeErrors.length > 0) {
-        const sample = localShapeErrors.slice(0, 3).join('; ');
-        throw new Error(`Invalid local sync payload: ${sample}`);
-    }
+    assertSyncPayloadShape(localDataRaw, 'local');
     const localNormalized = normalizeAppData(localDataRaw);
     let localData = purgeExpiredTombstones(localNormalized, nowIso, io.tombstoneRetentionDays).data;
 
@@ -1203,117 +1419,122 @@ export async function performSyncCycle(io: SyncCycleIO): Promise<SyncCycleResult
         localData = recoveredLocalData;
     }
 
-    io.onStep?.('read-remote');
-    const remoteDataRaw = await io.readRemote();
-    if (remoteDataRaw) {
-        const remoteShapeErrors = validateSyncPayloadShape(remoteDataRaw, 'remote');
-        if (remoteShapeErrors.length > 0) {
-            const sample = remoteShapeErrors.slice(0, 3).join('; ');
-            logWarn('Invalid remote sync payload shape', {
+    for (let attempt = 0; ; attempt += 1) {
+        io.onStep?.('read-remote');
+        const remoteDataRaw = await io.readRemote();
+        if (remoteDataRaw) {
+            assertSyncPayloadShape(remoteDataRaw, 'remote');
+        }
+        const remoteNormalized = normalizeAppData(remoteDataRaw || EMPTY_SYNC_PAYLOAD);
+        const remoteData = purgeExpiredTombstones(remoteNormalized, nowIso, io.tombstoneRetentionDays).data;
+
+        io.onStep?.('merge');
+        const mergeResult = io.mergeData
+            ? await io.mergeData(localData, remoteDataRaw ? remoteData : null)
+            : mergeAppDataWithStats(localData, remoteData);
+        const conflictCount = (mergeResult.stats.tasks.conflicts || 0)
+            + (mergeResult.stats.projects.conflicts || 0)
+            + (mergeResult.stats.sections.conflicts || 0)
+            + (mergeResult.stats.areas.conflicts || 0);
+        const nextSyncStatus: SyncCycleResult['status'] = conflictCount > 0 ? 'conflict' : 'success';
+        const conflictIds = [
+            ...(mergeResult.stats.tasks.conflictIds || []),
+            ...(mergeResult.stats.projects.conflictIds || []),
+            ...(mergeResult.stats.sections.conflictIds || []),
+            ...(mergeResult.stats.areas.conflictIds || []),
+        ].slice(0, 10);
+        const maxClockSkewMs = Math.max(
+            mergeResult.stats.tasks.maxClockSkewMs || 0,
+            mergeResult.stats.projects.maxClockSkewMs || 0,
+            mergeResult.stats.sections.maxClockSkewMs || 0,
+            mergeResult.stats.areas.maxClockSkewMs || 0
+        );
+        if (maxClockSkewMs > CLOCK_SKEW_THRESHOLD_MS) {
+            logWarn('Sync merge detected large clock skew', {
                 scope: 'sync',
                 context: {
-                    issues: remoteShapeErrors.length,
-                    sample,
+                    maxClockSkewMs: Math.round(maxClockSkewMs),
+                    thresholdMs: CLOCK_SKEW_THRESHOLD_MS,
                 },
             });
-            throw new Error(`Invalid remote sync payload: ${sample}`);
         }
-    }
-    const remoteNormalized = normalizeAppData(remoteDataRaw || { tasks: [], projects: [], sections: [], areas: [], settings: {} });
-    const remoteData = purgeExpiredTombstones(remoteNormalized, nowIso, io.tombstoneRetentionDays).data;
-
-    io.onStep?.('merge');
-    const mergeResult = mergeAppDataWithStats(localData, remoteData);
-    const conflictCount = (mergeResult.stats.tasks.conflicts || 0)
-        + (mergeResult.stats.projects.conflicts || 0)
-        + (mergeResult.stats.sections.conflicts || 0)
-        + (mergeResult.stats.areas.conflicts || 0);
-    const nextSyncStatus: SyncCycleResult['status'] = conflictCount > 0 ? 'conflict' : 'success';
-    const conflictIds = [
-        ...(mergeResult.stats.tasks.conflictIds || []),
-        ...(mergeResult.stats.projects.conflictIds || []),
-        ...(mergeResult.stats.sections.conflictIds || []),
-        ...(mergeResult.stats.areas.conflictIds || []),
-    ].slice(0, 10);
-    const maxClockSkewMs = Math.max(
-        mergeResult.stats.tasks.maxClockSkewMs || 0,
-        mergeResult.stats.projects.maxClockSkewMs || 0,
-        mergeResult.stats.sections.maxClockSkewMs || 0,
-        mergeResult.stats.areas.maxClockSkewMs || 0
-    );
-    if (maxClockSkewMs > CLOCK_SKEW_THRESHOLD_MS) {
-        logWarn('Sync merge detected large clock skew', {
-            scope: 'sync',
-            context: {
-                maxClockSkewMs: Math.round(maxClockSkewMs),
-                thresholdMs: CLOCK_SKEW_THRESHOLD_MS,
-            },
-        });
-    }
-    const timestampAdjustments = (mergeResult.stats.tasks.timestampAdjustments || 0)
-        + (mergeResult.stats.projects.timestampAdjustments || 0)
-        + (mergeResult.stats.sections.timestampAdjustments || 0)
-        + (mergeResult.stats.areas.timestampAdjustments || 0);
-    const historyEntry: SyncHistoryEntry = {
-        at: nowIso,
-        status: nextSyncStatus,
-        backend: io.historyContext?.backend,
-        type: io.historyContext?.type ?? 'merge',
-        conflicts: conflictCount,
-        conflictIds,
-        maxClockSkewMs,
-        timestampAdjustments,
-        details: io.historyContext?.details,
-    };
-    const nextHistory = appendSyncHistory(mergeResult.data.settings, historyEntry);
-    const nextMergedData: AppData = {
-        ...mergeResult.data,
-        settings: {
-            ...mergeResult.data.settings,
-            lastSyncAt: nowIso,
-            lastSyncStatus: nextSyncStatus,
-            lastSyncError: undefined,
-            lastSyncStats: mergeResult.stats,
-            lastSyncHistory: nextHistory,
-        },
-    };
-    const pruned = purgeExpiredTombstones(nextMergedData, nowIso, io.tombstoneRetentionDays);
-    if (pruned.removedTaskTombstones > 0 || pruned.removedAttachmentTombstones > 0 || pruned.removedPendingRemoteDeletes > 0) {
-        logWarn('Purged expired sync tombstones', {
-            scope: 'sync',
-            context: {
-                removedTaskTombstones: pruned.removedTaskTombstones,
-                removedAttachmentTombstones: pruned.removedAttachmentTombstones,
-                removedPendingRemoteDeletes: pruned.removedPendingRemoteDeletes,
-            },
-        });
-    }
-    const finalData = pruned.data;
-    const validationErrors = validateMergedSyncData(finalData);
-    if (validationErrors.length > 0) {
-        const sample = validationErrors.slice(0, 3).join('; ');
-        logWarn('Sync merge validation failed', {
-            scope: 'sync',
-            context: {
-                issues: validationErrors.length,
-                sample,
+        const timestampAdjustments = (mergeResult.stats.tasks.timestampAdjustments || 0)
+            + (mergeResult.stats.projects.timestampAdjustments || 0)
+            + (mergeResult.stats.sections.timestampAdjustments || 0)
+            + (mergeResult.stats.areas.timestampAdjustments || 0);
+        const historyEntry: SyncHistoryEntry = {
+            at: nowIso,
+            status: nextSyncStatus,
+            backend: io.historyContext?.backend,
+            type: io.historyContext?.type ?? 'merge',
+            conflicts: conflictCount,
+            conflictIds,
+            maxClockSkewMs,
+            timestampAdjustments,
+            details: io.historyContext?.details,
+        };
+        const nextHistory = appendSyncHistory(mergeResult.data.settings, historyEntry);
+        const nextMergedData: AppData = {
+            ...mergeResult.data,
+            settings: {
+                ...mergeResult.data.settings,
+                lastSyncAt: nowIso,
+                lastSyncStatus: nextSyncStatus,
+                lastSyncError: undefined,
+                lastSyncStats: mergeResult.stats,
+                lastSyncHistory: nextHistory,
             },
-        });
-        throw new Error(`Sync validation failed: ${sample}`);
-    }
+        };
+        const pruned = purgeExpiredTombstones(nextMergedData, nowIso, io.tombstoneRetentionDays);
+        if (pruned.removedTaskTombstones > 0 || pruned.removedAttachmentTombstones > 0 || pruned.removedPendingRemoteDeletes > 0) {
+            logWarn('Purged expired sync tombstones', {
+                scope: 'sync',
+                context: {
+                    removedTaskTombstones: pruned.removedTaskTombstones,
+                    removedAttachmentTombstones: pruned.removedAttachmentTombstones,
+                    removedPendingRemoteDeletes: pruned.removedPendingRemoteDeletes,
+                },
+            });
+        }
+        const finalData = pruned.data;
+        const validationErrors = validateMergedSyncData(finalData);
+        if (validationErrors.length > 0) {
+            const sample = validationErrors.slice(0, 3).join('; ');
+            logWarn('Sync merge validation failed', {
+                scope: 'sync',
+                context: {
+                    issues: validationErrors.length,
+                    sample,
+                },
+            });
+            throw new Error(`Sync validation failed: ${sample}`);
+        }
 
-    const finalDataWithPendingRemoteWrite = withPendingRemoteWriteFlag(finalData, nowIso);
-    io.onStep?.('write-local');
-    await io.writeLocal(finalDataWithPendingRemoteWrite);
+        const finalDataWithPendingRemoteWrite = withPendingRemoteWriteFlag(finalData, nowIso);
+        io.onStep?.('write-local');
+        await io.writeLocal(finalDataWithPendingRemoteWrite);
 
-    // Write local first so a local persistence failure cannot leave remote ahead.
-    io.onStep?.('write-remote');
-    await io.writeRemote(finalDataWithPendingRemoteWrite);
+        // Write local first so a local persistence failure cannot leave remote ahead.
+        io.onStep?.('write-remote');
+        try {
+            await io.writeRemote(finalDataWithPendingRemoteWrite);
+        } catch (error) {
+            if (!(error instanceof SyncConflictError) || attempt >= MAX_SYNC_CONFLICT_RETRIES) {
+                throw error;
+            }
+            // Another device wrote in between: pull its data and merge again.
+            logWarn('Remote changed during sync, merging again', {
+                scope: 'sync',
+                context: { attempt: attempt + 1 },
+            });
+            continue;
+        }
 
-    const persistedFinalData = clearPendingRemoteWriteFlag(finalDataWithPendingRemoteWrite);
-    if (persistedFinalData !== finalDataWithPendingRemoteWrite) {
-        await io.writeLocal(persistedFinalData);
-    }
+        const persistedFinalData = clearPendingRemoteWriteFlag(finalDataWithPendingRemoteWrite);
+        if (persistedFinalData !== finalDataWithPendingRemoteWrite) {
+            await io.writeLocal(persistedFinalData);
+        }
 
-    return { data: persistedFinalData, stats: mergeResult.stats, status: nextSyncStatus };
+        return { data: persistedFinalData, stats: mergeResult.stats, status: nextSyncStatus };
+    }
 }
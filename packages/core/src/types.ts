@@ -1,4 +1,5 @@
 import type { ExternalCalendarSubscription } from './ics';
+import type { AIProviderId } from './ai/types';
 
 export type TaskStatus = 'inbox' | 'next' | 'waiting' | 'someday' | 'reference' | 'done' | 'archived';
 
@@ -249,7 +250,8 @@ export interface AppData {
         weeklyReviewTime?: string; // HH:mm
         ai?: {
             enabled?: boolean;
-            provider?: 'gemini' | 'openai' | 'anthropic';
+            /** A built-in provider or the id of a desktop registry provider. */
+            provider?: AIProviderId;
             apiKey?: string;
             baseUrl?: string;
             model?: string;